@@ -10,8 +10,11 @@ pub use value::Value;
 pub mod set;
 pub use set::Set;
 
+use crate::Error;
+
 /// Uniquely identifies a card within a deck.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Card {
     /// The suite of this card.
     pub suite: Suite,
@@ -26,9 +29,9 @@ impl Card {
     }
 
     /// Converts a number in the range of [0, 52) to a card.
-    pub fn from_index(index: u8) -> Result<Self, String> {
-        if !(index < 52) {
-            Err(format!("Invalid card index: {}", index))
+    pub fn from_index(index: u8) -> Result<Self, Error> {
+        if index >= 52 {
+            Err(Error::InvalidCardIndex(index))
         } else {
             Ok(Self::new(
                 Suite::from_index(index / 13)?,
@@ -43,7 +46,7 @@ impl Card {
     }
 
     /// Creates a card from its string representation.
-    pub fn from_chars(chars: [char; 2]) -> Result<Self, String> {
+    pub fn from_chars(chars: [char; 2]) -> Result<Self, Error> {
         Ok(Self::new(
             Suite::from_char(chars[0])?,
             Value::from_char(chars[1])?,
@@ -56,17 +59,83 @@ impl Card {
     }
 }
 
+/// Cards are ordered by suite and then by value.
+///
+/// Spades, being trump, are ordered after every other suite.
+/// The other suites are ordered by Suite::to_index(),
+/// giving Heart < Club < Diamond < Spade.
+impl Ord for Card {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let key = |card: &Self| {
+            (
+                card.suite == Suite::Spade,
+                card.suite.to_index(),
+                card.value,
+            )
+        };
+        key(self).cmp(&key(other))
+    }
+}
+
+impl PartialOrd for Card {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::fmt::Display for Card {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} of {}", self.value, self.suite)
+    }
+}
+
+impl std::str::FromStr for Card {
+    type Err = Error;
+
+    /// Parses a card from its two character string representation,
+    /// ignoring surrounding whitespace.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let mut chars = trimmed.chars();
+        match (chars.next(), chars.next(), chars.next()) {
+            (Some(suite), Some(value), None) => {
+                Self::from_chars([suite, value])
+            }
+            _ => Err(Error::InvalidCardString(trimmed.to_string())),
+        }
+    }
+}
+
 /// Makes a randomly shuffled deck.
 pub fn make_shuffled() -> Vec<Card> {
+    make_shuffled_with(&mut rand::thread_rng())
+}
+
+/// Hashes a string, such as a game code, into a seed for a random
+/// number generator.
+///
+/// The hash is stable, so the same string always gives the same seed.
+/// For example, `game::dealer::ShuffledDealer::with_rng(
+/// StdRng::seed_from_u64(seed_from_str(code)))` always deals the same hands
+/// for the same code.
+pub fn seed_from_str(s: &str) -> u64 {
+    // 64 bit FNV-1a
+    s.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Makes a deck shuffled using a random number generator.
+///
+/// Using a seeded generator allows for reproducible decks.
+pub fn make_shuffled_with<R: rand::Rng>(rng: &mut R) -> Vec<Card> {
     use rand::seq::SliceRandom;
-    use rand::thread_rng;
 
-    let mut vec = Vec::new();
-    vec.reserve(52);
+    let mut vec = Vec::with_capacity(52);
     for i in 0..52 {
         vec.push(Card::from_index(i).unwrap());
     }
-    vec.shuffle(&mut thread_rng());
+    vec.shuffle(rng);
 
     vec
 }
@@ -86,4 +155,83 @@ mod test {
             assert!(cards_set.insert(*card));
         }
     }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            Ok(Card::new(Suite::Spade, Value::Ace)),
+            "SA".parse::<Card>()
+        );
+        assert_eq!(
+            Ok(Card::new(Suite::Heart, Value::Number(10))),
+            " HX\n".parse::<Card>()
+        );
+        assert_eq!(
+            Ok(Card::new(Suite::Club, Value::Number(2))),
+            "C2".parse::<Card>()
+        );
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        assert!("SAK".parse::<Card>().is_err());
+        assert!("".parse::<Card>().is_err());
+        assert!("   ".parse::<Card>().is_err());
+        assert_eq!(
+            Err(Error::InvalidSuiteCharacter('Z')),
+            "ZA".parse::<Card>()
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            "Ace of Spades",
+            Card::new(Suite::Spade, Value::Ace).to_string()
+        );
+        assert_eq!(
+            "10 of Hearts",
+            Card::new(Suite::Heart, Value::Number(10)).to_string()
+        );
+    }
+
+    #[test]
+    fn ordering() {
+        use rand::seq::SliceRandom;
+
+        let expected: Vec<Card> = ["H2", "HA", "C3", "CK", "DX", "S2", "SA"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        let mut cards = expected.clone();
+        cards.shuffle(&mut rand::thread_rng());
+        cards.sort();
+        assert_eq!(expected, cards);
+    }
+
+    #[test]
+    fn make_shuffled_with_seed() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let deck1 = make_shuffled_with(&mut StdRng::seed_from_u64(7));
+        let deck2 = make_shuffled_with(&mut StdRng::seed_from_u64(7));
+        let deck3 = make_shuffled_with(&mut StdRng::seed_from_u64(8));
+        assert_eq!(52, deck1.len());
+        assert_eq!(deck1, deck2);
+        assert_ne!(deck1, deck3);
+    }
+
+    #[test]
+    fn seed_from_str_is_stable() {
+        assert_eq!(
+            seed_from_str("family-game-3"),
+            seed_from_str("family-game-3")
+        );
+        assert_ne!(
+            seed_from_str("family-game-3"),
+            seed_from_str("family-game-4")
+        );
+        assert_eq!(0xcbf2_9ce4_8422_2325, seed_from_str(""));
+    }
 }
@@ -1,6 +1,7 @@
 //! Contains a set type for cards.
 
 use super::{Card, Suite};
+use crate::Error;
 
 /// A set type for cards.
 #[derive(Default, Copy, Clone, Eq, PartialEq)]
@@ -17,7 +18,7 @@ impl Set {
     /// Creates a set containing every card.
     pub fn full() -> Self {
         Self {
-            int: ((1 as u64) << 52) - 1,
+            int: (1_u64 << 52) - 1,
         }
     }
 
@@ -26,10 +27,26 @@ impl Set {
         // NOTE: this makes assumptions about card.to_index(),
         // is covered by tests
         Self {
-            int: (((1 as u64) << 13) - 1) << (suite.to_index() as u64 * 13),
+            int: ((1_u64 << 13) - 1) << (suite.to_index() as u64 * 13),
         }
     }
 
+    /// Creates a set from a whitespace separated list of cards,
+    /// such as "SA SK HQ D2".
+    ///
+    /// Each card is parsed with Card::from_str().
+    /// Fails if a card is invalid or listed more than once.
+    pub fn from_str_list(s: &str) -> Result<Self, Error> {
+        let mut set = Self::default();
+        for token in s.split_whitespace() {
+            let card = token.parse::<Card>()?;
+            if !set.insert(card) {
+                return Err(Error::DuplicateCard(card));
+            }
+        }
+        Ok(set)
+    }
+
     /// Inserts a card into a set.
     ///
     /// Returns true if the insert took place.
@@ -73,6 +90,52 @@ impl Set {
         self.int.count_ones() as usize
     }
 
+    /// Gets if every card in this set is also in another set.
+    pub fn is_subset(self, other: Self) -> bool {
+        self.int & other.int == self.int
+    }
+
+    /// Gets if every card in another set is also in this set.
+    pub fn is_superset(self, other: Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Gets if this set and another set have no cards in common.
+    pub fn is_disjoint(self, other: Self) -> bool {
+        self.int & other.int == 0
+    }
+
+    /// Gets the cards in this set of a single suite.
+    pub fn of_suite(self, suite: Suite) -> Self {
+        self & Self::suite(suite)
+    }
+
+    /// Gets the card in this set with the highest index.
+    ///
+    /// Within a single suite this is the card with the highest value.
+    /// Returns None if this set is empty.
+    pub fn highest(self) -> Option<Card> {
+        if self.is_empty() {
+            None
+        } else {
+            let index = 63 - self.int.leading_zeros() as u8;
+            Some(Card::from_index(index).unwrap())
+        }
+    }
+
+    /// Gets the card in this set with the lowest index.
+    ///
+    /// Within a single suite this is the card with the lowest value.
+    /// Returns None if this set is empty.
+    pub fn lowest(self) -> Option<Card> {
+        if self.is_empty() {
+            None
+        } else {
+            let index = self.int.trailing_zeros() as u8;
+            Some(Card::from_index(index).unwrap())
+        }
+    }
+
     /// Creates an iterator over all of the cards in this set.
     ///
     /// The iteration order is from lowest to highest index.
@@ -157,6 +220,27 @@ impl std::ops::Sub for Set {
     }
 }
 
+impl std::ops::BitOrAssign for Set {
+    /// In place set union operator.
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.int |= rhs.int;
+    }
+}
+
+impl std::ops::BitAndAssign for Set {
+    /// In place set intersection operator.
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.int &= rhs.int;
+    }
+}
+
+impl std::ops::SubAssign for Set {
+    /// In place set difference operator.
+    fn sub_assign(&mut self, rhs: Self) {
+        self.int &= !rhs.int;
+    }
+}
+
 impl std::ops::Not for Set {
     type Output = Self;
 
@@ -166,6 +250,33 @@ impl std::ops::Not for Set {
     }
 }
 
+// serialized as the raw bitset
+#[cfg(feature = "serde")]
+impl serde::Serialize for Set {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.int)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Set {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let int = <u64 as serde::Deserialize>::deserialize(deserializer)?;
+        if int & !Self::full().int != 0 {
+            Err(serde::de::Error::custom(format!(
+                "Card set {:#x} contains bits above index 51.",
+                int
+            )))
+        } else {
+            Ok(Self { int })
+        }
+    }
+}
+
 impl std::iter::Iterator for Iterator {
     type Item = Card;
 
@@ -286,9 +397,7 @@ mod test {
         }
         assert_eq!(52, hash_set.len());
 
-        for _ in Set::default().iter() {
-            unreachable!();
-        }
+        assert!(Set::default().iter().next().is_none());
     }
 
     #[test]
@@ -377,6 +486,32 @@ mod test {
         assert_eq!(set1 - set2, expected_result);
     }
 
+    #[test]
+    fn assigning_operators() {
+        let set1: Set = [1, 2, 3]
+            .iter()
+            .map(|x| Card::from_index(*x).unwrap())
+            .collect();
+        let set2: Set = [2, 3, 20]
+            .iter()
+            .map(|x| Card::from_index(*x).unwrap())
+            .collect();
+
+        let mut set = set1;
+        set |= set2;
+        assert_eq!(set1 | set2, set);
+
+        let mut set = set1;
+        set &= set2;
+        assert_eq!(set1 & set2, set);
+
+        let mut set = set1;
+        set -= set2;
+        assert_eq!(set1 - set2, set);
+        assert_eq!(1, set.len());
+        assert!(set.contains(Card::from_index(1).unwrap()));
+    }
+
     #[test]
     fn negate() {
         let mut set = Set::full();
@@ -385,4 +520,112 @@ mod test {
         assert_eq!(1, negated.len());
         assert!(negated.contains(Card::from_index(0).unwrap()));
     }
+
+    #[test]
+    fn highest_and_lowest() {
+        assert_eq!(None, Set::default().highest());
+        assert_eq!(None, Set::default().lowest());
+
+        let card = Card::new(Suite::Club, Value::Number(9));
+        let set: Set = [card].iter().collect();
+        assert_eq!(Some(card), set.highest());
+        assert_eq!(Some(card), set.lowest());
+
+        let set = Set::suite(Suite::Heart);
+        assert_eq!(Some(Card::new(Suite::Heart, Value::Ace)), set.highest());
+        assert_eq!(
+            Some(Card::new(Suite::Heart, Value::Number(2))),
+            set.lowest()
+        );
+
+        let set: Set = [
+            Card::new(Suite::Spade, Value::King),
+            Card::new(Suite::Diamond, Value::Number(3)),
+            Card::new(Suite::Heart, Value::Jack),
+        ]
+        .iter()
+        .collect();
+        assert_eq!(
+            Some(Card::new(Suite::Diamond, Value::Number(3))),
+            set.highest()
+        );
+        assert_eq!(Some(Card::new(Suite::Spade, Value::King)), set.lowest());
+    }
+
+    #[test]
+    fn of_suite() {
+        let hand: Set = ["SA", "S2", "HK", "D5", "DX", "D3"]
+            .iter()
+            .map(|s| s.parse::<Card>().unwrap())
+            .collect();
+        let diamonds = hand.of_suite(Suite::Diamond);
+        assert_eq!(3, diamonds.len());
+        for card in diamonds.iter() {
+            assert_eq!(Suite::Diamond, card.suite);
+            assert!(hand.contains(card));
+        }
+        assert!(hand.of_suite(Suite::Club).is_empty());
+        assert_eq!(
+            Set::suite(Suite::Heart),
+            Set::full().of_suite(Suite::Heart)
+        );
+    }
+
+    #[test]
+    fn subset_superset_disjoint() {
+        let empty = Set::default();
+        let hearts = Set::suite(Suite::Heart);
+        let spades = Set::suite(Suite::Spade);
+        let ace: Set = [Card::new(Suite::Heart, Value::Ace)].iter().collect();
+        let overlapping = ace | spades;
+
+        // nested
+        assert!(ace.is_subset(hearts));
+        assert!(!hearts.is_subset(ace));
+        assert!(hearts.is_superset(ace));
+        assert!(!ace.is_superset(hearts));
+        assert!(!ace.is_disjoint(hearts));
+
+        // overlapping
+        assert!(!overlapping.is_subset(hearts));
+        assert!(!overlapping.is_superset(hearts));
+        assert!(!overlapping.is_disjoint(hearts));
+
+        // disjoint
+        assert!(!spades.is_subset(hearts));
+        assert!(!spades.is_superset(hearts));
+        assert!(spades.is_disjoint(hearts));
+
+        // empty
+        assert!(empty.is_subset(hearts));
+        assert!(empty.is_subset(empty));
+        assert!(empty.is_superset(empty));
+        assert!(hearts.is_superset(empty));
+        assert!(!empty.is_superset(hearts));
+        assert!(empty.is_disjoint(hearts));
+        assert!(empty.is_disjoint(empty));
+        assert!(hearts.is_subset(hearts));
+        assert!(hearts.is_superset(hearts));
+    }
+
+    #[test]
+    fn from_str_list() {
+        let hand =
+            Set::from_str_list("SA SK SQ SJ SX S9 H2 H3 C4 C5 DA DK  DQ")
+                .unwrap();
+        assert_eq!(13, hand.len());
+        assert!(hand.contains(Card::new(Suite::Spade, Value::Number(10))));
+        assert!(hand.contains(Card::new(Suite::Diamond, Value::Queen)));
+        assert_eq!(Ok(Set::default()), Set::from_str_list(" "));
+
+        let ace_of_spades = Card::new(Suite::Spade, Value::Ace);
+        assert_eq!(
+            Err(Error::DuplicateCard(ace_of_spades)),
+            Set::from_str_list("SA HA SA")
+        );
+        assert_eq!(
+            Err(Error::InvalidCardString("S10".to_string())),
+            Set::from_str_list("SA S10")
+        );
+    }
 }
@@ -1,5 +1,8 @@
+use crate::Error;
+
 /// Enumeration for the suite of a card.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Suite {
     /// The trump suite.
     Spade,
@@ -13,13 +16,13 @@ pub enum Suite {
 
 impl Suite {
     /// Converts a value in the range of [0, 4) to a Suite.
-    pub fn from_index(index: u8) -> Result<Self, String> {
+    pub fn from_index(index: u8) -> Result<Self, Error> {
         match index {
             0 => Ok(Suite::Spade),
             1 => Ok(Suite::Heart),
             2 => Ok(Suite::Club),
             3 => Ok(Suite::Diamond),
-            _ => Err(format!("Invalid suite index: {}", index)),
+            _ => Err(Error::InvalidSuiteIndex(index)),
         }
     }
 
@@ -34,13 +37,13 @@ impl Suite {
     }
 
     /// Creates a Suite from its character representation.
-    pub fn from_char(c: char) -> Result<Self, String> {
+    pub fn from_char(c: char) -> Result<Self, Error> {
         match c {
             'S' => Ok(Suite::Spade),
             'H' => Ok(Suite::Heart),
             'C' => Ok(Suite::Club),
             'D' => Ok(Suite::Diamond),
-            _ => Err(format!("Invalid card suite character: '{}'", c)),
+            _ => Err(Error::InvalidSuiteCharacter(c)),
         }
     }
 
@@ -53,6 +56,22 @@ impl Suite {
             Suite::Diamond => 'D',
         }
     }
+
+    /// Gets an iterator over every suite in index order.
+    pub fn all() -> impl Iterator<Item = Suite> {
+        (0..4).map(|index| Self::from_index(index).unwrap())
+    }
+}
+
+impl std::fmt::Display for Suite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Suite::Spade => write!(f, "Spades"),
+            Suite::Heart => write!(f, "Hearts"),
+            Suite::Club => write!(f, "Clubs"),
+            Suite::Diamond => write!(f, "Diamonds"),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -72,4 +91,20 @@ mod test {
             assert_eq!(c, Suite::from_char(c).unwrap().to_char());
         }
     }
+
+    #[test]
+    fn display() {
+        assert_eq!("Spades", Suite::Spade.to_string());
+        assert_eq!("Hearts", Suite::Heart.to_string());
+        assert_eq!("Clubs", Suite::Club.to_string());
+        assert_eq!("Diamonds", Suite::Diamond.to_string());
+    }
+
+    #[test]
+    fn all() {
+        let suites: Vec<Suite> = Suite::all().collect();
+        assert_eq!(4, suites.len());
+        assert_eq!(Some(&Suite::Spade), suites.first());
+        assert_eq!(Some(&Suite::Diamond), suites.last());
+    }
 }
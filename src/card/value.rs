@@ -1,7 +1,10 @@
+use crate::Error;
+
 /// Enumeration for the value of a card.
 ///
 /// The values are ordered as 2, ..., 10, Jack, Queen, King, Ace.
 #[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Value {
     /// The inner number is in the range of [2, 10].
     Number(u8),
@@ -17,14 +20,14 @@ pub enum Value {
 
 impl Value {
     /// Converts a number in the range of [0, 13) to a Value
-    pub fn from_index(index: u8) -> Result<Self, String> {
+    pub fn from_index(index: u8) -> Result<Self, Error> {
         match index {
             0..=8 => Ok(Value::Number(index + 2)),
             9 => Ok(Value::Jack),
             10 => Ok(Value::Queen),
             11 => Ok(Value::King),
             12 => Ok(Value::Ace),
-            _ => Err(format!("Invalid card value index: {}", index)),
+            _ => Err(Error::InvalidValueIndex(index)),
         }
     }
 
@@ -40,15 +43,15 @@ impl Value {
     }
 
     /// Converts a character into a Value.
-    pub fn from_char(c: char) -> Result<Self, String> {
+    pub fn from_char(c: char) -> Result<Self, Error> {
         match c {
-            '2'..='9' => Ok(Value::Number(c as u8 - '0' as u8)),
+            '2'..='9' => Ok(Value::Number(c as u8 - b'0')),
             'X' => Ok(Value::Number(10)),
             'J' => Ok(Value::Jack),
             'Q' => Ok(Value::Queen),
             'K' => Ok(Value::King),
             'A' => Ok(Value::Ace),
-            _ => Err(format!("Invalid card value character: '{}'", c)),
+            _ => Err(Error::InvalidValueCharacter(c)),
         }
     }
 
@@ -56,13 +59,33 @@ impl Value {
     pub fn to_char(self) -> char {
         match self {
             Value::Number(10) => 'X',
-            Value::Number(number) => ('0' as u8 + number) as char,
+            Value::Number(number) => (b'0' + number) as char,
             Value::Jack => 'J',
             Value::Queen => 'Q',
             Value::King => 'K',
             Value::Ace => 'A',
         }
     }
+
+    /// Gets the next higher value.
+    ///
+    /// Returns None for an Ace.
+    pub fn successor(self) -> Option<Value> {
+        Self::from_index(self.to_index() + 1).ok()
+    }
+
+    /// Gets the next lower value.
+    ///
+    /// Returns None for a 2.
+    pub fn predecessor(self) -> Option<Value> {
+        Self::from_index(self.to_index().checked_sub(1)?).ok()
+    }
+
+    /// Gets an iterator over every value in index order,
+    /// from 2 up to Ace.
+    pub fn all() -> impl Iterator<Item = Value> {
+        (0..13).map(|index| Self::from_index(index).unwrap())
+    }
 }
 
 impl std::fmt::Debug for Value {
@@ -71,6 +94,18 @@ impl std::fmt::Debug for Value {
     }
 }
 
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Number(number) => write!(f, "{}", number),
+            Value::Jack => write!(f, "Jack"),
+            Value::Queen => write!(f, "Queen"),
+            Value::King => write!(f, "King"),
+            Value::Ace => write!(f, "Ace"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -99,4 +134,36 @@ mod test {
         assert!(Value::Queen < Value::King);
         assert!(Value::King < Value::Ace);
     }
+
+    #[test]
+    fn display() {
+        assert_eq!("7", Value::Number(7).to_string());
+        assert_eq!("10", Value::Number(10).to_string());
+        assert_eq!("Jack", Value::Jack.to_string());
+        assert_eq!("King", Value::King.to_string());
+        assert_eq!("Ace", Value::Ace.to_string());
+    }
+
+    #[test]
+    fn all() {
+        let values: Vec<Value> = Value::all().collect();
+        assert_eq!(13, values.len());
+        assert_eq!(Some(&Value::Number(2)), values.first());
+        assert_eq!(Some(&Value::Ace), values.last());
+    }
+
+    #[test]
+    fn successor_and_predecessor() {
+        let mut value = Value::Number(2);
+        assert_eq!(None, value.predecessor());
+        for expected in Value::all().skip(1) {
+            let next = value.successor().unwrap();
+            assert_eq!(expected, next);
+            assert!(next > value);
+            assert_eq!(Some(value), next.predecessor());
+            value = next;
+        }
+        assert_eq!(Value::Ace, value);
+        assert_eq!(None, value.successor());
+    }
 }
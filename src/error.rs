@@ -0,0 +1,321 @@
+//! Contains the `Error` enum returned by every fallible function.
+
+use crate::game::Action;
+use crate::Card;
+
+/// The errors that can occur when using this library.
+///
+/// Displays as a human readable message.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Error {
+    /// A card was played by a player when it was not their turn.
+    NotYourTurn,
+    /// A bid was made by a player when it was not their turn.
+    NotYourTurnToBid,
+    /// A player attempted to wait when the game was waiting on them.
+    WaitingOnYou,
+    /// A card was played into a trick that every player has played into.
+    TrickComplete,
+    /// A card was played that is not in the player's hand.
+    CardNotInHand,
+    /// A card was played that does not follow the rules of the trick.
+    IllegalCard,
+    /// A card was played by a player that has not seen their hand.
+    HandNotSeen,
+    /// A player requested to see their hand when they already could.
+    HandAlreadySeen,
+    /// A player's hand does not have the expected number of cards.
+    HandSizeMismatch {
+        /// The number of cards in the hand.
+        actual: usize,
+        /// The number of cards the hand should have.
+        expected: u8,
+    },
+    /// A card was played before bidding was complete.
+    BiddingIncomplete,
+    /// Both players in a team bid nil or blind nil.
+    IllegalBid,
+    /// A team bid more than 13 tricks.
+    TeamBidTooHigh,
+    /// A player bid more than 13 tricks.
+    BidTooHigh(u8),
+    /// A player bid blind nil after seeing their hand.
+    BlindNilAfterSeeingCards,
+    /// A player bid nil after their teammate rejected their nil bid.
+    NilAlreadyRejected,
+    /// A player confirmed a nil bid when their teammate has none pending.
+    NoTeammateNilPending,
+    /// A player confirmed a nil bid when no one has one pending.
+    NoNilPending,
+    /// An action was performed after the game was over.
+    GameOver(Action),
+    /// A spectator attempted to perform an action.
+    SpectatorAction,
+    /// There is no locally performed action to undo.
+    NothingToUndo,
+    /// A player's own notification was applied to their view.
+    OwnNotification,
+    /// A bid or play notification was received for a player whose turn
+    /// it is not.
+    NotificationOutOfTurn {
+        /// The player that the notification is from.
+        player: crate::Player,
+        /// The player that the game is waiting on.
+        expected: crate::Player,
+    },
+    /// Applying a notification in a sequence of notifications failed.
+    NotificationFailed {
+        /// The index of the notification that failed.
+        index: usize,
+        /// The reason that the notification failed.
+        error: Box<Error>,
+    },
+    /// A player has no bid when every player should have bid.
+    MissingBid(crate::Player),
+    /// A player index outside of [0, 4).
+    InvalidPlayerIndex(u8),
+    /// A team index outside of [0, 2).
+    InvalidTeamIndex(u8),
+    /// A suite index outside of [0, 4).
+    InvalidSuiteIndex(u8),
+    /// A card value index outside of [0, 13).
+    InvalidValueIndex(u8),
+    /// A card index outside of [0, 52).
+    InvalidCardIndex(u8),
+    /// A character that does not represent a suite.
+    InvalidSuiteCharacter(char),
+    /// A character that does not represent a card value.
+    InvalidValueCharacter(char),
+    /// A string that is not two characters representing a card.
+    InvalidCardString(String),
+    /// A string that does not represent a bid.
+    InvalidBidString(String),
+    /// An encoded round whose length is not a multiple of five.
+    InvalidEncodedRoundLength(usize),
+    /// An encoded round with more than 13 tricks.
+    TooManyTricks,
+    /// An encoded trick where a player played a card after a player that
+    /// has not played yet.
+    CardAfterMissingCard(crate::Player),
+    /// A card was played more than once in a round.
+    CardPlayedTwice(Card),
+    /// A card was listed more than once in a list of cards.
+    DuplicateCard(Card),
+    /// Hands dealt to different players share cards.
+    OverlappingHands,
+    /// Hands dealt to the players do not contain every card.
+    IncompleteDeal(usize),
+    /// A snapshot's number of deals does not match the rounds played.
+    DealCountMismatch {
+        /// The number of deals in the snapshot.
+        actual: u32,
+        /// The number of deals the rounds played need.
+        expected: u32,
+    },
+    /// A player was dealt a hand that does not have 13 cards.
+    UnevenDeal {
+        /// The player that was dealt the hand.
+        player: crate::Player,
+        /// The number of cards in the hand.
+        num_cards: usize,
+    },
+    /// A share code that was not created by State::to_share_code() or that
+    /// can not be replayed with the given dealer.
+    InvalidShareCode,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NotYourTurn => {
+                write!(f, "Can not play a card when it is not your turn")
+            }
+            Error::NotYourTurnToBid => {
+                write!(f, "It is not your turn to bid.")
+            }
+            Error::WaitingOnYou => {
+                write!(f, "Can not wait when the game is waiting on you.")
+            }
+            Error::TrickComplete => write!(
+                f,
+                "Can not play a card into a trick that is already won."
+            ),
+            Error::CardNotInHand => {
+                write!(f, "You can not play a card not in your hand.")
+            }
+            Error::IllegalCard => write!(f, "Can not play the given card."),
+            Error::HandNotSeen => {
+                write!(f, "Can not play a card without seeing your hand.")
+            }
+            Error::HandAlreadySeen => write!(
+                f,
+                "Can not request to see your cards when you can already \
+                see them."
+            ),
+            Error::HandSizeMismatch { actual, expected } => write!(
+                f,
+                "Hand has {} cards when {} were expected.",
+                actual, expected
+            ),
+            Error::BiddingIncomplete => {
+                write!(f, "Can not play a card, bidding is not complete.")
+            }
+            Error::IllegalBid => {
+                write!(f, "Both players in team can not bid nil or blind nil.")
+            }
+            Error::TeamBidTooHigh => {
+                write!(f, "Can not bid more than 13 tricks as a team.")
+            }
+            Error::BidTooHigh(tricks) => {
+                write!(f, "Can not bid more than 13 tricks, got {}.", tricks)
+            }
+            Error::BlindNilAfterSeeingCards => {
+                write!(f, "Can not bid blind nil as you have seen your cards.")
+            }
+            Error::NilAlreadyRejected => write!(
+                f,
+                "You can not bid nil if your partner has already rejected \
+                your nil bid this bidding round."
+            ),
+            Error::NoTeammateNilPending => write!(
+                f,
+                "Can not confirm a nil bid, your teammate does not have a \
+                nil bid pending."
+            ),
+            Error::NoNilPending => write!(
+                f,
+                "Can not confirm a nil bid, no one has a nil bid pending."
+            ),
+            Error::GameOver(action) => match action {
+                Action::PlayCard(_) => {
+                    write!(f, "Can not play a card, the game is over.")
+                }
+                Action::Concede => {
+                    write!(f, "Can not concede when the game is over.")
+                }
+                Action::Wait => write!(f, "Can not wait when the game is over"),
+                Action::SeeCards => write!(
+                    f,
+                    "Can not request to see your cards when the game is over"
+                ),
+                action => {
+                    write!(f, "Can not perform {:?}, the game is over.", action)
+                }
+            },
+            Error::SpectatorAction => {
+                write!(f, "Spectators can not perform actions.")
+            }
+            Error::NothingToUndo => write!(f, "There is no action to undo."),
+            Error::OwnNotification => write!(
+                f,
+                "Notifications from a player can not be applied to the \
+                player's own view of the game."
+            ),
+            Error::NotificationOutOfTurn { player, expected } => write!(
+                f,
+                "Received a notification from {} when waiting on {}.",
+                player, expected
+            ),
+            Error::NotificationFailed { index, error } => {
+                write!(f, "Notification {} failed: {}", index, error)
+            }
+            Error::MissingBid(player) => {
+                write!(f, "Internal error, no bid for player {}", player)
+            }
+            Error::InvalidPlayerIndex(index) => {
+                write!(f, "Invalid player index: {}", index)
+            }
+            Error::InvalidTeamIndex(index) => {
+                write!(f, "Invalid team index: {}", index)
+            }
+            Error::InvalidSuiteIndex(index) => {
+                write!(f, "Invalid suite index: {}", index)
+            }
+            Error::InvalidValueIndex(index) => {
+                write!(f, "Invalid card value index: {}", index)
+            }
+            Error::InvalidCardIndex(index) => {
+                write!(f, "Invalid card index: {}", index)
+            }
+            Error::InvalidSuiteCharacter(c) => {
+                write!(f, "Invalid card suite character: '{}'", c)
+            }
+            Error::InvalidValueCharacter(c) => {
+                write!(f, "Invalid card value character: '{}'", c)
+            }
+            Error::InvalidCardString(s) => write!(
+                f,
+                "Card string must be exactly two characters: \"{}\"",
+                s
+            ),
+            Error::InvalidBidString(s) => write!(f, "Invalid bid: \"{}\"", s),
+            Error::InvalidEncodedRoundLength(length) => {
+                write!(f, "Invalid encoded round length: {}", length)
+            }
+            Error::TooManyTricks => {
+                write!(f, "A round can not have more than 13 tricks.")
+            }
+            Error::CardAfterMissingCard(player) => write!(
+                f,
+                "{} played a card after a player that has not played.",
+                player
+            ),
+            Error::CardPlayedTwice(card) => {
+                write!(f, "Card played more than once: {:?}", card)
+            }
+            Error::DuplicateCard(card) => {
+                write!(f, "Card listed more than once: {:?}", card)
+            }
+            Error::OverlappingHands => write!(f, "Hands must not share cards."),
+            Error::IncompleteDeal(num_cards) => write!(
+                f,
+                "Hands must contain all 52 cards, found {}.",
+                num_cards
+            ),
+            Error::DealCountMismatch { actual, expected } => write!(
+                f,
+                "Snapshot has {} deals when {} were expected.",
+                actual, expected
+            ),
+            Error::UnevenDeal { player, num_cards } => write!(
+                f,
+                "{} must be dealt 13 cards, found {}.",
+                player, num_cards
+            ),
+            Error::InvalidShareCode => write!(f, "Invalid share code."),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Player;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            "It is not your turn to bid.",
+            Error::NotYourTurnToBid.to_string()
+        );
+        assert_eq!(
+            "Can not concede when the game is over.",
+            Error::GameOver(Action::Concede).to_string()
+        );
+        assert_eq!(
+            "Internal error, no bid for player Player 2",
+            Error::MissingBid(Player::Two).to_string()
+        );
+        assert_eq!(
+            "Notification 3 failed: There is no action to undo.",
+            Error::NotificationFailed {
+                index: 3,
+                error: Box::new(Error::NothingToUndo)
+            }
+            .to_string()
+        );
+    }
+}
@@ -2,6 +2,7 @@ use crate::{Bid, Card};
 
 /// Contains all of the possible actions for a player to perform.
 #[derive(Hash, Eq, PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Action {
     /// Do nothing.  Waiting for another player to perform an action.
     Wait,
@@ -15,4 +16,9 @@ pub enum Action {
     MakeBid(Bid),
     /// Play a card.
     PlayCard(Card),
+    /// Concede the game on behalf of the player's team.
+    ///
+    /// Any player may concede for their team at any point before the game
+    /// is over, so this is never included in View::get_allowed_actions().
+    Concede,
 }
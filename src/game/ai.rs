@@ -0,0 +1,572 @@
+//! Contains a trait for computer players and simple implementations of it.
+
+use super::{Action, State, Status, View};
+use crate::card::{self, Suite, Value};
+use crate::scoring::{self, ScoringRules};
+use crate::{Bid, Card, Player};
+
+/// Trait for choosing the action that a computer player performs.
+pub trait Strategy {
+    /// Chooses an action to perform given a player's view of the game.
+    ///
+    /// The action should be one of `view.get_allowed_actions()`.
+    fn choose_action(&mut self, view: &View) -> Action;
+}
+
+/// Strategy that sees its cards as soon as possible and otherwise
+/// picks uniformly from the allowed actions.
+///
+/// Chooses Action::Wait if there are no allowed actions.
+#[derive(Default)]
+pub struct RandomStrategy {}
+
+impl Strategy for RandomStrategy {
+    fn choose_action(&mut self, view: &View) -> Action {
+        use rand::seq::IteratorRandom;
+
+        let actions = view.get_allowed_actions();
+        if actions.contains(&Action::SeeCards) {
+            return Action::SeeCards;
+        }
+        actions
+            .into_iter()
+            .choose(&mut rand::thread_rng())
+            .unwrap_or(Action::Wait)
+    }
+}
+
+/// Estimates the number of tricks that a hand will take.
+///
+/// Counts one trick for each of:
+/// - the ace, king, and queen of spades,
+/// - each spade beyond the third,
+/// - each other ace,
+/// - each other king that has another card of its suite to protect it,
+/// - each other suite the hand is void in, if the hand has a spade to
+///   trump with.
+///
+/// The estimate is at most 13.
+pub fn estimate_tricks(hand: card::Set) -> u8 {
+    let spades = hand.of_suite(Suite::Spade);
+    let mut tricks = spades
+        .iter()
+        .filter(|card| card.value >= Value::Queen)
+        .count()
+        + spades.len().saturating_sub(3);
+    for suite in [Suite::Heart, Suite::Club, Suite::Diamond].iter() {
+        let cards = hand.of_suite(*suite);
+        if cards.contains(Card::new(*suite, Value::Ace)) {
+            tricks += 1;
+        }
+        if cards.len() >= 2 && cards.contains(Card::new(*suite, Value::King)) {
+            tricks += 1;
+        }
+        if cards.is_empty() && !spades.is_empty() {
+            tricks += 1;
+        }
+    }
+    tricks.min(13) as u8
+}
+
+/// Gets the highest take bid that estimate_tricks() supports for a hand
+/// while staying legal alongside a teammate's bid.
+///
+/// Is Take(0) if the teammate's bid already takes every trick.
+pub fn max_reasonable_bid(hand: card::Set, teammate_bid: Option<Bid>) -> Bid {
+    let teammate_tricks = match teammate_bid {
+        Some(Bid::Take(tricks)) => tricks,
+        _ => 0,
+    };
+    Bid::Take(estimate_tricks(hand).min(13u8.saturating_sub(teammate_tricks)))
+}
+
+/// Estimates the chance in [0, 1] that a hand takes a trick after
+/// bidding nil.
+///
+/// Each card is given a chance of being forced to win a trick that grows
+/// with its value, with spades being more dangerous than other suites.
+/// The ace of spades always wins a trick.
+pub fn nil_risk(hand: card::Set) -> f32 {
+    let safe = hand.iter().fold(1.0, |safe, card| {
+        let rank = card.value.to_index() as f32 / 12.0;
+        let danger = if card.suite == Suite::Spade {
+            rank * rank
+        } else {
+            rank * rank * rank * 0.5
+        };
+        safe * (1.0 - danger)
+    });
+    1.0 - safe
+}
+
+/// Estimates the points a hand adds to its team by bidding nil and by
+/// bidding its max_reasonable_bid(), returned in that order.
+///
+/// A nil loses its bonus with the chance given by nil_risk() and earns
+/// it otherwise.
+/// A take bid is assumed to be made and is worth the increase in its
+/// team's bid value over bidding 0 tricks.
+/// The value of nil is negative infinity if it can not be bid alongside
+/// the teammate's bid.
+/// A teammate that has not bid yet is treated as bidding 0 tricks.
+pub fn nil_vs_take_ev(
+    hand: card::Set,
+    teammate_bid: Option<Bid>,
+    rules: &ScoringRules,
+) -> (f32, f32) {
+    let base_bid = teammate_bid.unwrap_or(Bid::Take(0));
+    let value_over_zero = |bid: Bid| {
+        let value = scoring::get_bid_value_with_rules(bid, base_bid, rules)
+            as f32
+            - scoring::get_bid_value_with_rules(Bid::Take(0), base_bid, rules)
+                as f32;
+        10.0 * value
+    };
+
+    let nil_ev = if Bid::Nil.get_compatibility_error(teammate_bid).is_some() {
+        f32::NEG_INFINITY
+    } else {
+        let risk = nil_risk(hand);
+        value_over_zero(Bid::Nil) * (1.0 - 2.0 * risk)
+    };
+    let take_ev = value_over_zero(max_reasonable_bid(hand, teammate_bid));
+    (nil_ev, take_ev)
+}
+
+/// Chooses the pair of bids with the highest expected value for a team
+/// that can see both of its players' hands.
+///
+/// Each player either bids nil or their max_reasonable_bid() given the
+/// other's bid.
+/// A pair with a nil is expected to win its value with the chance that
+/// the nil succeeds, given by nil_risk(), and to lose it otherwise.
+/// Pairs without a nil are assumed to be made.
+/// The bids returned are always legal together.
+pub fn optimal_team_bid(
+    hand1: card::Set,
+    hand2: card::Set,
+    rules: &ScoringRules,
+) -> (Bid, Bid) {
+    let value = |bid1: Bid, bid2: Bid| {
+        10.0 * scoring::get_bid_value_with_rules(bid1, bid2, rules) as f32
+    };
+
+    let take1 = max_reasonable_bid(hand1, None);
+    let take2 = max_reasonable_bid(hand2, Some(take1));
+    let mut best = ((take1, take2), value(take1, take2));
+
+    let nil_candidates = [
+        (Bid::Nil, max_reasonable_bid(hand2, Some(Bid::Nil)), hand1),
+        (max_reasonable_bid(hand1, Some(Bid::Nil)), Bid::Nil, hand2),
+    ];
+    for (bid1, bid2, nil_hand) in nil_candidates.iter() {
+        let risk = nil_risk(*nil_hand);
+        let ev = value(*bid1, *bid2) * (1.0 - 2.0 * risk);
+        if ev > best.1 {
+            best = ((*bid1, *bid2), ev);
+        }
+    }
+    best.0
+}
+
+/// Suggests a lead that pressures an opponent's nil bid.
+///
+/// Returns None unless it is this player's turn to lead and an opponent
+/// has bid nil or blind nil without taking a trick yet.
+/// Leads the lowest card of the suite that the nil bidder could hold the
+/// fewest cards of, skipping suites they are known to be void in, so
+/// that they are more likely to be left holding only high cards in it.
+pub fn best_nil_attack(view: &View) -> Option<Card> {
+    let player = view.get_player();
+    if view.is_spectator()
+        || view.get_status() != Status::WaitingForPlay(player)
+        || view.get_trick().get_suite().is_some()
+    {
+        return None;
+    }
+    let nil_bidder = [player.next(), player.next().teammate()]
+        .iter()
+        .copied()
+        .find(|opponent| {
+            matches!(
+                view.get_bid(*opponent),
+                Some(Bid::Nil) | Some(Bid::BlindNil)
+            ) && view.get_num_tricks(*opponent) == 0
+        })?;
+
+    let playable = view
+        .get_trick()
+        .get_playable_cards(view.get_hand()?, view.is_trump_broken());
+    let possible = view.possible_cards(nil_bidder);
+    Suite::all()
+        .filter(|suite| !view.is_known_void(nil_bidder, *suite))
+        .filter(|suite| !playable.of_suite(*suite).is_empty())
+        .min_by_key(|suite| possible.of_suite(*suite).len())
+        .and_then(|suite| playable.of_suite(suite).lowest())
+}
+
+/// Strategy that bids based on estimate_tricks() and plays its lowest
+/// legal card.
+///
+/// Always approves a teammate's nil bid.
+#[derive(Default)]
+pub struct HeuristicStrategy {}
+
+impl Strategy for HeuristicStrategy {
+    fn choose_action(&mut self, view: &View) -> Action {
+        let actions = view.get_allowed_actions();
+        if actions.contains(&Action::SeeCards) {
+            return Action::SeeCards;
+        }
+        if actions.contains(&Action::AllowNil) {
+            return Action::AllowNil;
+        }
+        if let Some(hand) = view.get_hand() {
+            let bid = max_reasonable_bid(
+                hand,
+                view.get_bid(view.get_player().teammate()),
+            );
+            if actions.contains(&Action::MakeBid(bid)) {
+                return Action::MakeBid(bid);
+            }
+        }
+        actions
+            .iter()
+            .filter_map(|action| match action {
+                Action::PlayCard(card) => Some(*card),
+                _ => None,
+            })
+            .min_by_key(|card| (card.value, *card))
+            .map(Action::PlayCard)
+            .or_else(|| actions.into_iter().next())
+            .unwrap_or(Action::Wait)
+    }
+}
+
+/// Suggests a better card than the one a player chose, for reviewing
+/// plays in a practice game.
+///
+/// The state is the game just before the card was played.
+/// Plays are judged greedily by their effect on the active trick alone,
+/// see greedy_rank().
+/// Returns None if it is not the player's turn to play or if no legal card
+/// is judged better than the card played.
+pub fn critique_play(
+    state: &State,
+    player: Player,
+    played: Card,
+) -> Option<Card> {
+    let view = state.create_view(player);
+    if view.get_status() != Status::WaitingForPlay(player) {
+        return None;
+    }
+    let playable = view
+        .get_trick()
+        .get_playable_cards(view.get_hand()?, view.is_trump_broken());
+    let best = playable
+        .iter()
+        .max_by_key(|card| greedy_rank(&view, *card))?;
+    if playable.contains(played)
+        && greedy_rank(&view, played) >= greedy_rank(&view, best)
+    {
+        None
+    } else {
+        Some(best)
+    }
+}
+
+/// Ranks a legal play by its effect on the active trick, where a higher
+/// rank is better.
+///
+/// A player that bid nil wants to lose the trick with their highest card.
+/// A player whose teammate is winning the trick wants to keep it that way
+/// while throwing their lowest card.
+/// Otherwise a player wants to win the trick with their lowest winning
+/// card, or to throw their lowest card if they can not win.
+/// Spades are kept over other suites when throwing a card.
+fn greedy_rank(view: &View, card: Card) -> (bool, bool, i8) {
+    let player = view.get_player();
+    let wins = view.winning_plays().contains(card);
+    let value = card.value.to_index() as i8;
+    let not_spade = card.suite != Suite::Spade;
+    match view.get_bid(player) {
+        Some(Bid::Nil) | Some(Bid::BlindNil) => (!wins, true, value),
+        _ => match view.get_trick().current_winner() {
+            Some((winner, _)) if winner == player.teammate() => {
+                (!wins, not_spade, -value)
+            }
+            _ => (wins, not_spade, -value),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::game::{Event, Notification, Response, State, Status};
+    use crate::Player;
+
+    /// Creates a view where the player has seen a hand and it is their turn
+    /// to bid after a number of other bids.
+    fn view_before_bid(player: Player, hand: card::Set, bids: &[Bid]) -> View {
+        let mut view = View::new(player);
+        view.perform_action(Action::SeeCards).unwrap();
+        view.handle_response(Response::Cards(hand)).unwrap();
+        for (bidder, bid) in Player::Two.iter().zip(bids.iter()) {
+            view.handle_notification(Notification {
+                player: bidder,
+                event: Event::MakeBid(*bid),
+            })
+            .unwrap();
+        }
+        view
+    }
+
+    /// Creates a hand of every spade, which takes every trick.
+    fn strong_hand() -> card::Set {
+        card::Set::suite(Suite::Spade)
+    }
+
+    /// Creates a hand of low hearts and clubs, which takes no tricks.
+    fn weak_hand() -> card::Set {
+        (2..9)
+            .map(|n| Card::new(Suite::Heart, Value::Number(n)))
+            .chain((2..8).map(|n| Card::new(Suite::Club, Value::Number(n))))
+            .collect()
+    }
+
+    #[test]
+    fn estimate() {
+        assert_eq!(13, estimate_tricks(strong_hand()));
+        assert_eq!(0, estimate_tricks(weak_hand()));
+
+        let mixed: card::Set = ["SA", "SK", "S2", "HA", "HK", "H3", "CK"]
+            .iter()
+            .chain(["D2", "D3", "D4", "D5", "D6", "D7"].iter())
+            .map(|s| s.parse::<Card>().unwrap())
+            .collect();
+        assert_eq!(4, estimate_tricks(mixed));
+    }
+
+    #[test]
+    fn max_reasonable() {
+        let strong = strong_hand();
+        let weak = weak_hand();
+
+        assert_eq!(Bid::Take(0), max_reasonable_bid(weak, None));
+        assert_eq!(Bid::Take(13), max_reasonable_bid(strong, None));
+        assert_eq!(Bid::Take(13), max_reasonable_bid(strong, Some(Bid::Nil)));
+        assert_eq!(
+            Bid::Take(4),
+            max_reasonable_bid(strong, Some(Bid::Take(9)))
+        );
+        assert_eq!(
+            Bid::Take(0),
+            max_reasonable_bid(strong, Some(Bid::Take(14)))
+        );
+
+        for hand in [weak, strong].iter() {
+            for teammate_bid in crate::scoring::bid::Generator::default() {
+                let bid = max_reasonable_bid(*hand, Some(teammate_bid));
+                assert!(bid
+                    .get_compatibility_error(Some(teammate_bid))
+                    .is_none());
+            }
+        }
+    }
+
+    #[test]
+    fn nil_vs_take() {
+        let rules = ScoringRules::default();
+        let strong = strong_hand();
+        let weak = weak_hand();
+
+        assert_eq!(1.0, nil_risk(strong));
+        assert!(nil_risk(weak) < 0.5);
+
+        let (nil_ev, take_ev) = nil_vs_take_ev(strong, None, &rules);
+        assert!(take_ev > nil_ev);
+        let (nil_ev, take_ev) =
+            nil_vs_take_ev(strong, Some(Bid::Take(5)), &rules);
+        assert!(take_ev > nil_ev);
+
+        let (nil_ev, take_ev) = nil_vs_take_ev(weak, None, &rules);
+        assert!(nil_ev > take_ev);
+        let (nil_ev, take_ev) =
+            nil_vs_take_ev(weak, Some(Bid::Take(5)), &rules);
+        assert!(nil_ev > take_ev);
+
+        // nil can not be bid alongside a nil
+        let (nil_ev, _) = nil_vs_take_ev(weak, Some(Bid::Nil), &rules);
+        assert_eq!(f32::NEG_INFINITY, nil_ev);
+    }
+
+    #[test]
+    fn optimal_team() {
+        let rules = ScoringRules::default();
+        let parse = |cards: &[&str]| -> card::Set {
+            cards.iter().map(|s| s.parse::<Card>().unwrap()).collect()
+        };
+
+        let hand1 = parse(&[
+            "SA", "SK", "SQ", "SJ", "SX", "S9", "HA", "HK", "H2", "C2", "C3",
+            "D2", "D3",
+        ]);
+        let hand2 = parse(&[
+            "S8", "S7", "S6", "S5", "CA", "CK", "C4", "DA", "DK", "D4", "H3",
+            "H4", "H5",
+        ]);
+        assert_eq!(8, estimate_tricks(hand1));
+        assert_eq!(5, estimate_tricks(hand2));
+        assert_eq!(
+            (Bid::Take(8), Bid::Take(5)),
+            optimal_team_bid(hand1, hand2, &rules)
+        );
+
+        // the team bid is limited to 13 tricks
+        let spades = strong_hand();
+        let hearts = card::Set::suite(Suite::Heart);
+        assert_eq!(
+            (Bid::Take(13), Bid::Take(0)),
+            optimal_team_bid(spades, hearts, &rules)
+        );
+        let (bid1, bid2) = optimal_team_bid(hearts, spades, &rules);
+        assert!(bid2.get_compatibility_error(Some(bid1)).is_none());
+    }
+
+    #[test]
+    fn nil_attack() {
+        let hand: card::Set = ["D2", "D3", "D4", "D5", "D6", "D7", "D8"]
+            .iter()
+            .chain(["C2", "C3", "C4", "H2", "H3", "H4"].iter())
+            .map(|s| s.parse::<Card>().unwrap())
+            .collect();
+
+        // player three bids nil, approved by player one
+        let mut view = View::new(Player::Two);
+        view.perform_action(Action::SeeCards).unwrap();
+        view.handle_response(Response::Cards(hand)).unwrap();
+        view.perform_action(Action::MakeBid(Bid::Take(3))).unwrap();
+        assert_eq!(None, best_nil_attack(&view));
+        let notifications = [
+            (Player::Three, Event::MakeBid(Bid::Nil)),
+            (Player::One, Event::ApprovesNil(true)),
+            (Player::Four, Event::MakeBid(Bid::Take(3))),
+            (Player::One, Event::MakeBid(Bid::Take(4))),
+        ];
+        for (player, event) in notifications.iter() {
+            view.handle_notification(Notification {
+                player: *player,
+                event: *event,
+            })
+            .unwrap();
+        }
+        assert_eq!(Status::WaitingForPlay(Player::Two), view.get_status());
+
+        // the nil bidder can hold the fewest diamonds
+        assert_eq!(Some("D2".parse::<Card>().unwrap()), best_nil_attack(&view));
+
+        // no one to attack without a nil
+        let view = view_before_bid(Player::Two, hand, &[]);
+        assert_eq!(None, best_nil_attack(&view));
+    }
+
+    #[test]
+    fn heuristic_bids() {
+        let mut strategy = HeuristicStrategy::default();
+
+        let strong = strong_hand();
+        let view = view_before_bid(Player::Two, strong, &[]);
+        assert_eq!(
+            Action::MakeBid(Bid::Take(13)),
+            strategy.choose_action(&view)
+        );
+
+        let view = view_before_bid(Player::Two, weak_hand(), &[]);
+        assert_eq!(
+            Action::MakeBid(Bid::Take(0)),
+            strategy.choose_action(&view)
+        );
+
+        // limited by the teammate's bid
+        let view = view_before_bid(
+            Player::Four,
+            strong,
+            &[Bid::Take(10), Bid::Take(2)],
+        );
+        assert_eq!(
+            Action::MakeBid(Bid::Take(3)),
+            strategy.choose_action(&view)
+        );
+    }
+
+    /// Plays a full game where every player uses a strategy, checking that
+    /// only allowed actions are chosen.
+    fn play_full_game(strategy: &mut dyn Strategy) {
+        let mut state = State::default();
+        for _ in 0..100_000 {
+            let player = match state.get_status() {
+                Status::WaitingForBid(player)
+                | Status::WaitingForNilConfirmation(player)
+                | Status::WaitingForPlay(player) => player,
+                Status::GameOver => return,
+            };
+            let mut view = state.create_view(player);
+            let action = strategy.choose_action(&view);
+            assert!(view.get_allowed_actions().contains(&action));
+            let event = view.perform_action(action).unwrap().unwrap();
+            let (response, _) = state.handle_event(player, event);
+            assert!(!matches!(response, Response::Err(_)));
+        }
+        panic!("The game did not end.");
+    }
+
+    #[test]
+    fn random_full_game() {
+        play_full_game(&mut RandomStrategy::default());
+    }
+
+    #[test]
+    fn heuristic_full_game() {
+        play_full_game(&mut HeuristicStrategy::default());
+    }
+
+    #[test]
+    fn critique() {
+        use crate::game::dealer::FixedDealer;
+
+        let hands = crate::player::Array::from_array([
+            card::Set::suite(Suite::Spade),
+            card::Set::suite(Suite::Heart),
+            card::Set::suite(Suite::Club),
+            card::Set::suite(Suite::Diamond),
+        ]);
+        let mut state = State::new(Box::new(FixedDealer::new(hands).unwrap()));
+        for player in Player::Two.iter() {
+            state.handle_event(player, Event::SeeCards);
+            state.handle_event(player, Event::MakeBid(Bid::Take(3)));
+        }
+        let card = |s: &str| s.parse::<Card>().unwrap();
+
+        // only player two may play
+        assert_eq!(None, critique_play(&state, Player::Three, card("C2")));
+
+        state.handle_event(Player::Two, Event::PlayCard(card("H5")));
+
+        // player three can not win, so should throw their lowest card
+        assert_eq!(
+            Some(card("C2")),
+            critique_play(&state, Player::Three, card("CA"))
+        );
+        assert_eq!(None, critique_play(&state, Player::Three, card("C2")));
+        state.handle_event(Player::Three, Event::PlayCard(card("C2")));
+        state.handle_event(Player::Four, Event::PlayCard(card("D2")));
+
+        // player one should trump with their lowest spade
+        assert_eq!(
+            Some(card("S2")),
+            critique_play(&state, Player::One, card("SA"))
+        );
+        assert_eq!(None, critique_play(&state, Player::One, card("S2")));
+    }
+}
@@ -1,6 +1,6 @@
 //! Contains a trait for dealing cards to players and a default implementation.
 
-use crate::{card, player, Player};
+use crate::{card, player, Card, Error, Player};
 
 /// Trait for creating each player's hand.
 ///
@@ -10,24 +10,151 @@ pub trait Dealer {
     fn deal_cards(&mut self) -> player::Array<card::Set>;
 }
 
-/// Default implementation of the Dealer Trait.
+/// Trait for ordering a deck before it is dealt.
+pub trait ShuffleStrategy {
+    /// Creates an ordering of all 52 cards.
+    fn order(&mut self) -> Vec<Card>;
+}
+
+/// Shuffle strategy where every ordering is equally likely.
 ///
-/// Uses card::make_shuffled() to deal cards.
+/// Uses the thread random number generator by default.
 #[derive(Default)]
-pub struct ShuffledDealer {}
+pub struct UniformShuffle {
+    rng: Option<Box<dyn rand::RngCore>>,
+}
+
+impl UniformShuffle {
+    /// Creates a shuffle strategy that uses a random number generator.
+    pub fn with_rng<R: rand::RngCore + 'static>(rng: R) -> Self {
+        Self {
+            rng: Some(Box::new(rng)),
+        }
+    }
+}
+
+impl ShuffleStrategy for UniformShuffle {
+    fn order(&mut self) -> Vec<Card> {
+        match &mut self.rng {
+            Some(rng) => card::make_shuffled_with(rng),
+            None => card::make_shuffled(),
+        }
+    }
+}
+
+/// Default implementation of the Dealer Trait.
+///
+/// Deals the cards in the order given by a ShuffleStrategy,
+/// which is UniformShuffle by default.
+pub struct ShuffledDealer {
+    strategy: Box<dyn ShuffleStrategy>,
+}
+
+impl ShuffledDealer {
+    /// Creates a dealer that uses a shuffle strategy to order the deck.
+    pub fn new(strategy: Box<dyn ShuffleStrategy>) -> Self {
+        Self { strategy }
+    }
+
+    /// Creates a dealer that uniformly shuffles using a
+    /// random number generator.
+    ///
+    /// Using a seeded generator allows for reproducible deals.
+    pub fn with_rng<R: rand::RngCore + 'static>(rng: R) -> Self {
+        Self::new(Box::new(UniformShuffle::with_rng(rng)))
+    }
+}
+
+impl Default for ShuffledDealer {
+    fn default() -> Self {
+        Self::new(Box::new(UniformShuffle::default()))
+    }
+}
 
 impl Dealer for ShuffledDealer {
     fn deal_cards(&mut self) -> player::Array<card::Set> {
-        let mut hands = player::Array::<card::Set>::default();
-        let mut player = Player::One;
-        for card in card::make_shuffled().iter() {
-            hands[player].insert(*card);
-            player = player.next();
+        deal_in_order(&self.strategy.order())
+    }
+}
+
+/// Dealer that always deals a deck that is in index order
+/// and then cut at a fixed point.
+///
+/// Useful for reproducible hands.
+pub struct CutDealer {
+    cut: u8,
+}
+
+impl CutDealer {
+    /// Creates a dealer whose deck starts with the card with the given index.
+    ///
+    /// Cuts of 52 or more wrap around.
+    pub fn new(cut: u8) -> Self {
+        Self { cut: cut % 52 }
+    }
+}
+
+impl Dealer for CutDealer {
+    fn deal_cards(&mut self) -> player::Array<card::Set> {
+        let mut cards: Vec<Card> =
+            (0..52).map(|i| Card::from_index(i).unwrap()).collect();
+        cards.rotate_left(self.cut as usize);
+        deal_in_order(&cards)
+    }
+}
+
+/// Dealer that always deals the same hands.
+///
+/// Useful for testing.
+pub struct FixedDealer {
+    hands: player::Array<card::Set>,
+}
+
+impl FixedDealer {
+    /// Creates a dealer that always deals a set of hands.
+    ///
+    /// Fails if the hands overlap, do not contain every card,
+    /// or do not each have 13 cards.
+    pub fn new(hands: player::Array<card::Set>) -> Result<Self, Error> {
+        let mut all = card::Set::default();
+        for hand in hands.iter() {
+            if !all.is_disjoint(*hand) {
+                return Err(Error::OverlappingHands);
+            }
+            all |= *hand;
+        }
+        if all != card::Set::full() {
+            return Err(Error::IncompleteDeal(all.len()));
+        }
+        for player in Player::One.iter() {
+            if hands[player].len() != 13 {
+                return Err(Error::UnevenDeal {
+                    player,
+                    num_cards: hands[player].len(),
+                });
+            }
         }
-        hands
+        Ok(Self { hands })
+    }
+}
+
+impl Dealer for FixedDealer {
+    fn deal_cards(&mut self) -> player::Array<card::Set> {
+        self.hands
     }
 }
 
+/// Deals cards one at a time to each player, starting with Player::One.
+fn deal_in_order(cards: &[Card]) -> player::Array<card::Set> {
+    let mut hands = player::Array::<card::Set>::default();
+    let mut player = Player::One;
+    for card in cards.iter() {
+        hands[player].insert(*card);
+        player = player.next();
+    }
+    hands
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -46,4 +173,97 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn shuffled_with_rng() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut dealer1 = ShuffledDealer::with_rng(StdRng::seed_from_u64(3));
+        let mut dealer2 = ShuffledDealer::with_rng(StdRng::seed_from_u64(3));
+        for _ in 0..3 {
+            let hands1 = dealer1.deal_cards();
+            let hands2 = dealer2.deal_cards();
+            for player in Player::One.iter() {
+                assert_eq!(13, hands1[player].len());
+                assert_eq!(hands1[player], hands2[player]);
+            }
+        }
+    }
+
+    #[test]
+    fn cut() {
+        let mut dealer = CutDealer::new(3);
+        let hands = dealer.deal_cards();
+        for player in Player::One.iter() {
+            assert_eq!(13, hands[player].len());
+        }
+        // the deck starts at index 3, so player one gets 3, 7, ..., 51
+        for (i, player) in Player::One.iter().enumerate() {
+            for j in 0..13 {
+                let index = ((3 + i + 4 * j) % 52) as u8;
+                assert!(
+                    hands[player].contains(Card::from_index(index).unwrap())
+                );
+            }
+        }
+        assert_eq!(
+            hands[Player::One],
+            CutDealer::new(55).deal_cards()[Player::One]
+        );
+    }
+
+    #[test]
+    fn fixed() {
+        let hands = player::Array::from_array([
+            card::Set::suite(card::Suite::Spade),
+            card::Set::suite(card::Suite::Heart),
+            card::Set::suite(card::Suite::Club),
+            card::Set::suite(card::Suite::Diamond),
+        ]);
+        let mut dealer = FixedDealer::new(hands).unwrap();
+        for _ in 0..2 {
+            let dealt = dealer.deal_cards();
+            for player in Player::One.iter() {
+                assert_eq!(hands[player], dealt[player]);
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_invalid() {
+        let overlapping = player::Array::from_array([
+            card::Set::suite(card::Suite::Spade),
+            card::Set::suite(card::Suite::Spade),
+            card::Set::suite(card::Suite::Club),
+            card::Set::suite(card::Suite::Diamond),
+        ]);
+        assert!(FixedDealer::new(overlapping).is_err());
+
+        let missing = player::Array::from_array([
+            card::Set::suite(card::Suite::Spade),
+            card::Set::suite(card::Suite::Heart),
+            card::Set::suite(card::Suite::Club),
+            card::Set::default(),
+        ]);
+        assert!(FixedDealer::new(missing).is_err());
+
+        // every card is dealt, but player one has 14 and player two 12
+        let mut uneven = player::Array::from_array([
+            card::Set::suite(card::Suite::Spade),
+            card::Set::suite(card::Suite::Heart),
+            card::Set::suite(card::Suite::Club),
+            card::Set::suite(card::Suite::Diamond),
+        ]);
+        let moved = uneven[Player::Two].iter().next().unwrap();
+        uneven[Player::Two].remove(moved);
+        uneven[Player::One].insert(moved);
+        assert_eq!(
+            Err(Error::UnevenDeal {
+                player: Player::One,
+                num_cards: 14
+            }),
+            FixedDealer::new(uneven).map(|_| ())
+        );
+    }
 }
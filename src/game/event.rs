@@ -3,6 +3,7 @@ use crate::Bid;
 
 /// Actions that a player can perform that changes a game's state.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// A player wishes to see their cards, forfeiting their right to bid
     /// blind nil if they have not already done so.
@@ -16,4 +17,7 @@ pub enum Event {
     ApprovesNil(bool),
     /// A player plays a card.
     PlayCard(Card),
+    /// A player concedes the game on behalf of their team,
+    /// making the other team the winner.
+    Concede,
 }
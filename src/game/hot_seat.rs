@@ -0,0 +1,116 @@
+use super::{Action, Response, State, View};
+use crate::{Error, Player};
+
+/// A game where every player shares a single device, taking turns
+/// acting through the view of the player that the game is waiting on.
+pub struct HotSeatGame {
+    /// The state of the game.
+    state: State,
+    /// The player currently acting.
+    seat: Player,
+}
+
+impl Default for HotSeatGame {
+    /// Creates a new hot seat game that uses
+    /// game::dealer::ShuffledDealer to deal cards.
+    fn default() -> Self {
+        Self::new(State::default())
+    }
+}
+
+impl HotSeatGame {
+    /// Creates a hot seat game from a game state.
+    pub fn new(state: State) -> Self {
+        let mut game = Self {
+            state,
+            seat: Player::One,
+        };
+        game.advance();
+        game
+    }
+
+    /// Gets the player currently acting.
+    ///
+    /// Once the game is over this is the last player to have acted.
+    pub fn current_player(&self) -> Player {
+        self.seat
+    }
+
+    /// Creates the view of the player currently acting.
+    ///
+    /// This only contains the hand of the acting player.
+    pub fn current_view(&self) -> View {
+        self.state.create_view(self.seat)
+    }
+
+    /// Performs an action as the player currently acting and then
+    /// moves to the next player that the game is waiting on.
+    pub fn apply(&mut self, action: Action) -> Result<(), Error> {
+        let event = self.current_view().perform_action(action)?;
+        if let Some(event) = event {
+            if let (Response::Err(error), _) =
+                self.state.handle_event(self.seat, event)
+            {
+                return Err(error);
+            }
+        }
+        self.advance();
+        Ok(())
+    }
+
+    /// Gets the state of the game.
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    /// Moves the seat to the player that the game is waiting on.
+    fn advance(&mut self) {
+        if let Some(player) = self.state.current_player() {
+            self.seat = player;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::game::Status;
+    use crate::Bid;
+
+    #[test]
+    fn bidding() {
+        let mut game = HotSeatGame::default();
+        let mut first_hand = None;
+        for player in Player::Two.iter() {
+            assert_eq!(player, game.current_player());
+            let view = game.current_view();
+            assert_eq!(player, view.get_player());
+            assert_eq!(None, view.get_hand());
+
+            game.apply(Action::SeeCards).unwrap();
+            assert_eq!(player, game.current_player());
+            let hand = game.current_view().get_hand();
+            assert_eq!(13, hand.unwrap().len());
+            first_hand = first_hand.or(hand);
+
+            game.apply(Action::MakeBid(Bid::Take(3))).unwrap();
+
+            // after the seat changes the previous player's hand is hidden
+            // and only the new player's own hand, if seen, is shown
+            let view = game.current_view();
+            assert_eq!(player.next(), view.get_player());
+            if player.next() == Player::Two {
+                assert_eq!(first_hand, view.get_hand());
+            } else {
+                assert_eq!(None, view.get_hand());
+            }
+        }
+
+        assert_eq!(Player::Two, game.current_player());
+        assert_eq!(
+            Status::WaitingForPlay(Player::Two),
+            game.state().get_status()
+        );
+        assert!(game.apply(Action::MakeBid(Bid::Take(3))).is_err());
+    }
+}
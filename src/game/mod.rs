@@ -34,22 +34,38 @@
 mod action;
 pub use action::Action;
 
+pub mod ai;
+
 pub mod dealer;
 
 mod event;
 pub use event::Event;
 
+mod hot_seat;
+pub use hot_seat::HotSeatGame;
+
 mod notification;
 pub use notification::Notification;
 
+mod phase;
+pub use phase::Phase;
+
+mod play_outcome;
+pub use play_outcome::PlayOutcome;
+
 mod public_state;
 use public_state::PublicState;
 
 mod response;
 pub use response::Response;
 
+mod session;
+pub use session::Session;
+
+mod share_code;
+
 mod state;
-pub use state::State;
+pub use state::{validate_log, State, StateSnapshot};
 
 mod status;
 pub use status::Status;
@@ -0,0 +1,13 @@
+/// The phase of the current round.
+///
+/// Is a coarser version of `Status` that does not contain the player
+/// being waited on.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Phase {
+    /// Players are bidding or confirming nil bids.
+    Bidding,
+    /// Players are playing cards.
+    Playing,
+    /// The game is over.
+    GameOver,
+}
@@ -0,0 +1,14 @@
+use crate::{Player, TeamRoundResult};
+
+/// The consequences of a card being played that completed a trick.
+///
+/// Is returned by `State::handle_event_with_outcome()` so that clients
+/// do not have to recompute the result of a play.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayOutcome {
+    /// The player that won the completed trick.
+    pub trick_winner: Player,
+    /// The results of each team if the play completed the round.
+    pub round_result: Option<[TeamRoundResult; 2]>,
+}
@@ -1,11 +1,12 @@
-use super::Status;
+use super::{Action, Status};
 use crate::{
-    card, player, scoring, trick, Bid, Card, Player, Score, TeamRoundResult,
-    Trick,
+    card, player, scoring, trick, Bid, Card, Error, Player, Score,
+    TeamRoundResult, Trick,
 };
 
 /// Game state that is viewable by all players.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PublicState {
     /// The current score.
     scores: [Score; 2],
@@ -13,6 +14,8 @@ pub struct PublicState {
     round_results: Vec<[TeamRoundResult; 2]>,
     /// The index of the dealer for this round.
     dealer: Player,
+    /// The number of rounds that have been completed.
+    round_number: u32,
     /// If each player has seen their cards.
     seen_cards: player::Array<bool>,
     /// If a trump card has been played yet.
@@ -28,6 +31,15 @@ pub struct PublicState {
     tricks_taken: player::Array<u8>,
     /// The current trick.
     trick: Trick,
+    /// The tricks that have been completed this round.
+    completed_tricks: Vec<Trick>,
+    /// The cards each player has played this round.
+    cards_played: player::Array<card::Set>,
+    /// The index of the team that conceded the game.
+    conceded_team: Option<u8>,
+    /// The suites each player is known to be void in this round,
+    /// indexed by card::Suite::to_index().
+    voids: player::Array<[bool; 4]>,
 }
 
 impl Default for PublicState {
@@ -36,6 +48,7 @@ impl Default for PublicState {
             scores: [Score::default(), Score::default()],
             round_results: Vec::new(),
             dealer: Player::One,
+            round_number: 0,
             seen_cards: player::Array::from_value(&false),
             trump_broken: false,
             pending_nil_player: None,
@@ -43,6 +56,10 @@ impl Default for PublicState {
             bids: player::Array::default(),
             tricks_taken: player::Array::from_value(&0),
             trick: Trick::new(Player::Two),
+            completed_tricks: Vec::new(),
+            cards_played: player::Array::default(),
+            conceded_team: None,
+            voids: player::Array::default(),
         }
     }
 }
@@ -53,11 +70,39 @@ impl PublicState {
         self.scores
     }
 
+    /// Sets the score of both teams.
+    #[cfg(test)]
+    pub(super) fn set_scores(&mut self, scores: [Score; 2]) {
+        self.scores = scores;
+    }
+
     /// Get the results of all completed rounds.
     pub fn get_round_results(&self) -> &Vec<[TeamRoundResult; 2]> {
         &self.round_results
     }
 
+    /// Gets the number of the current round, starting at 0.
+    ///
+    /// This is the number of rounds that have been completed.
+    pub fn get_round_number(&self) -> u32 {
+        self.round_number
+    }
+
+    /// Gets the dealer of the current round.
+    ///
+    /// The dealer moves to the next player at the end of each round.
+    pub fn get_dealer(&self) -> Player {
+        self.dealer
+    }
+
+    /// Gets the first player to bid in the current round,
+    /// the player after the dealer.
+    ///
+    /// This player also leads the first trick.
+    pub fn get_first_bidder(&self) -> Player {
+        self.dealer.next()
+    }
+
     /// Gets if the user can see their cards.
     pub fn can_see_cards(&self, player: Player) -> bool {
         self.seen_cards[player]
@@ -81,19 +126,99 @@ impl PublicState {
         self.bids[player]
     }
 
+    /// Gets if every player has bid this round and no nil bid
+    /// is waiting for confirmation.
+    pub fn bidding_complete(&self) -> bool {
+        self.pending_nil_player.is_none()
+            && self.bids.iter().all(|bid| bid.is_some())
+    }
+
     /// Gets the number of tricks that a player has taken this round.
     pub fn get_num_tricks(&self, player: Player) -> u8 {
         self.tricks_taken[player]
     }
 
+    /// Gets the number of cards that a player has played this round.
+    pub fn get_num_cards_played(&self, player: Player) -> u8 {
+        let tricks_complete: u8 = self.tricks_taken.iter().sum();
+        tricks_complete + self.trick.get_card(player).map_or(0, |_| 1)
+    }
+
+    /// Gets a team's result for this round if they take every remaining
+    /// trick, giving the remaining tricks to a player that did not bid nil.
+    ///
+    /// Returns None if either player on the team has not bid yet, as a
+    /// result can not be scored without both bids, or if the team index
+    /// is not 0 or 1.
+    pub fn best_case_remaining(&self, team: u8) -> Option<TeamRoundResult> {
+        let [first, second] = player::team_members(team).ok()?;
+        let bids = [self.bids[first]?, self.bids[second]?];
+        let mut tricks_taken =
+            [self.tricks_taken[first], self.tricks_taken[second]];
+        let tricks_complete: u8 = self.tricks_taken.iter().sum();
+        let taker = match bids[0] {
+            Bid::Nil | Bid::BlindNil => 1,
+            Bid::Take(_) => 0,
+        };
+        tricks_taken[taker] += 13 - tricks_complete;
+        Some(TeamRoundResult { bids, tricks_taken })
+    }
+
+    /// Gets a trick completed this round along with the player and card
+    /// that won it.
+    ///
+    /// Returns None if fewer than `index + 1` tricks have been
+    /// completed this round.
+    pub fn completed_trick(
+        &self,
+        index: usize,
+    ) -> Option<(Trick, Player, Card)> {
+        let trick = *self.completed_tricks.get(index)?;
+        match trick.get_status() {
+            trick::Status::Won(player, card) => Some((trick, player, card)),
+            trick::Status::Waiting(_) => None,
+        }
+    }
+
+    /// Gets every card that has been played this round.
+    pub fn get_cards_played(&self) -> card::Set {
+        self.cards_played
+            .iter()
+            .fold(card::Set::default(), |all, cards| all | *cards)
+    }
+
+    /// Gets the cards a player has played this round.
+    pub fn get_cards_played_by(&self, player: Player) -> card::Set {
+        self.cards_played[player]
+    }
+
+    /// Gets the suites each player is known to be void in, indexed by
+    /// card::Suite::to_index().
+    ///
+    /// A player is known to be void in a suite if they did not follow it
+    /// when it was led this round.
+    pub fn inferred_voids(&self) -> player::Array<[bool; 4]> {
+        self.voids
+    }
+
     /// Gets a copy of the current trick.
     pub fn get_trick(&self) -> Trick {
         self.trick
     }
 
+    /// Gets the index of the team that won the game.
+    ///
+    /// Returns None if the game is not over.
+    pub fn get_winning_team_index(&self) -> Option<u8> {
+        match self.conceded_team {
+            Some(team) => Some(1 - team),
+            None => scoring::get_winning_team_index(self.get_scores()),
+        }
+    }
+
     /// Gets the current status of this game.
     pub fn get_status(&self) -> Status {
-        if scoring::get_winning_team_index(self.get_scores()).is_some() {
+        if self.get_winning_team_index().is_some() {
             return Status::GameOver;
         }
 
@@ -101,7 +226,7 @@ impl PublicState {
             return Status::WaitingForNilConfirmation(bidding_nil.teammate());
         }
 
-        for player in self.dealer.next().iter() {
+        for player in self.get_first_bidder().iter() {
             if self.bids[player].is_none() {
                 return Status::WaitingForBid(player);
             }
@@ -117,12 +242,11 @@ impl PublicState {
 
     /// Internal function that gets the bids of every player or returns
     /// an error due to a missing bid.
-    fn get_bids(&self) -> Result<player::Array<Bid>, String> {
+    fn get_bids(&self) -> Result<player::Array<Bid>, Error> {
         let mut bids = player::Array::from_value(&Bid::Nil);
         for player in Player::One.iter() {
-            bids[player] = self.bids[player].ok_or_else(|| {
-                format!("Internal error, no bid for player {}", player)
-            })?;
+            bids[player] =
+                self.bids[player].ok_or(Error::MissingBid(player))?;
         }
         Ok(bids)
     }
@@ -130,7 +254,17 @@ impl PublicState {
     /// Internal function called after a card has been played.
     ///
     /// Used by on_card_played() and unchecked_on_card_played().
-    fn after_card_played(&mut self) -> Result<(), String> {
+    fn after_card_played(
+        &mut self,
+        player: Player,
+        card: Card,
+    ) -> Result<(), Error> {
+        self.cards_played[player].insert(card);
+        if let Some(suite) = self.trick.get_suite() {
+            if card.suite != suite {
+                self.voids[player][suite.to_index() as usize] = true;
+            }
+        }
         if let trick::Status::Won(winning_player, winning_card) =
             self.trick.get_status()
         {
@@ -139,6 +273,7 @@ impl PublicState {
             if winning_card.suite == crate::card::Suite::Spade {
                 self.trump_broken = true;
             }
+            self.completed_tricks.push(self.trick);
             self.trick = Trick::new(winning_player);
 
             let tricks_complete: u8 = self.tricks_taken.iter().sum();
@@ -152,17 +287,30 @@ impl PublicState {
                 self.scores[0] += results[0].get_score();
                 self.scores[1] += results[1].get_score();
                 self.dealer = self.dealer.next();
-                self.seen_cards.fill(&false);
-                self.trump_broken = false;
-                self.nil_rejected.fill(&false);
-                self.bids.fill(&None);
-                self.tricks_taken.fill(&0);
-                self.trick = Trick::new(self.dealer.next());
+                self.round_number += 1;
+                self.restart_round();
             }
         }
         Ok(())
     }
 
+    /// Resets everything tracked for the current round, returning to the
+    /// start of bidding with the same dealer.
+    ///
+    /// The scores and the results of completed rounds are kept.
+    pub fn restart_round(&mut self) {
+        self.seen_cards.fill(&false);
+        self.trump_broken = false;
+        self.pending_nil_player = None;
+        self.nil_rejected.fill(&false);
+        self.bids.fill(&None);
+        self.tricks_taken.fill(&0);
+        self.trick = Trick::new(self.get_first_bidder());
+        self.completed_tricks.clear();
+        self.cards_played.fill(&card::Set::default());
+        self.voids.fill(&[false; 4]);
+    }
+
     /// Call when a player plays a card and we have the player's hand available
     /// to ensure that it is valid for them to play the card.
     ///
@@ -172,29 +320,44 @@ impl PublicState {
         player: Player,
         card: Card,
         hand: &mut card::Set,
-    ) -> Result<(), String> {
+    ) -> Result<(), Error> {
+        self.check_card_played(player, card, *hand)?;
+        self.trick.play_card(player, card)?;
+        hand.remove(card);
+        self.after_card_played(player, card)
+    }
+
+    /// Checks that a player holding a hand may play a card without
+    /// modifying the state.
+    pub fn check_card_played(
+        &self,
+        player: Player,
+        card: Card,
+        hand: card::Set,
+    ) -> Result<(), Error> {
         if !hand.contains(card) {
-            return Err("You can not play a card not in your hand.".to_string());
+            return Err(Error::CardNotInHand);
         };
         match self.get_status() {
             Status::WaitingForBid(_) | Status::WaitingForNilConfirmation(_) => {
-                Err("Can not play a card, bidding is not complete.".to_string())
-            }
-            Status::GameOver => {
-                Err("Can not play a card, the game is over.".to_string())
+                Err(Error::BiddingIncomplete)
             }
+            Status::GameOver => Err(Error::GameOver(Action::PlayCard(card))),
             Status::WaitingForPlay(_) => {
-                // attempt to play the card
                 if !self
                     .trick
-                    .get_playable_cards(*hand, self.trump_broken)
+                    .get_playable_cards(hand, self.trump_broken)
                     .contains(card)
                 {
-                    return Err("Can not play the given card.".to_string());
+                    return Err(Error::IllegalCard);
+                }
+                match self.trick.get_status() {
+                    trick::Status::Won(_, _) => Err(Error::TrickComplete),
+                    trick::Status::Waiting(expected) if expected != player => {
+                        Err(Error::NotYourTurn)
+                    }
+                    trick::Status::Waiting(_) => Ok(()),
                 }
-                self.trick.play_card(player, card)?;
-                hand.remove(card);
-                self.after_card_played()
             }
         }
     }
@@ -205,45 +368,46 @@ impl PublicState {
         &mut self,
         player: Player,
         card: Card,
-    ) -> Result<(), String> {
+    ) -> Result<(), Error> {
         match self.get_status() {
             Status::WaitingForBid(_) | Status::WaitingForNilConfirmation(_) => {
-                Err("Can not play a card, bidding is not complete.".to_string())
-            }
-            Status::GameOver => {
-                Err("Can not play a card, the game is over.".to_string())
+                Err(Error::BiddingIncomplete)
             }
+            Status::GameOver => Err(Error::GameOver(Action::PlayCard(card))),
             Status::WaitingForPlay(_) => {
                 self.trick.play_card(player, card)?;
-                self.after_card_played()
+                self.after_card_played(player, card)
             }
         }
     }
 
     /// Handles a player making their bid.
-    pub fn on_bid(&mut self, player: Player, bid: Bid) -> Result<(), String> {
+    pub fn on_bid(&mut self, player: Player, bid: Bid) -> Result<(), Error> {
+        self.check_bid(player, bid)?;
+        if bid == Bid::Nil {
+            self.pending_nil_player = Some(player);
+        } else {
+            self.bids[player] = Some(bid);
+        }
+        Ok(())
+    }
+
+    /// Checks that a player may make a bid without modifying the state.
+    pub fn check_bid(&self, player: Player, bid: Bid) -> Result<(), Error> {
         if self.get_status() != Status::WaitingForBid(player) {
-            return Err("It is not your turn to bid.".to_string());
+            return Err(Error::NotYourTurnToBid);
         }
         if bid == Bid::BlindNil && self.seen_cards[player] {
-            return Err("Can not bid blind nil as you have seen your cards."
-                .to_string());
+            return Err(Error::BlindNilAfterSeeingCards);
         }
         if let Some(bid_error) =
             bid.get_compatibility_error(self.bids[player.teammate()])
         {
-            return Err(bid_error.to_string());
+            return Err(bid_error);
         }
 
-        if bid == Bid::Nil {
-            if self.nil_rejected[player] {
-                return Err("You can not bid nil if your partner has \
-                already rejected your nil bid this bidding round."
-                    .to_string());
-            }
-            self.pending_nil_player = Some(player);
-        } else {
-            self.bids[player] = Some(bid);
+        if bid == Bid::Nil && self.nil_rejected[player] {
+            return Err(Error::NilAlreadyRejected);
         }
         Ok(())
     }
@@ -254,29 +418,48 @@ impl PublicState {
         self.seen_cards[player] = true;
     }
 
+    /// Handles a player conceding the game for their team.
+    pub fn on_concede(&mut self, player: Player) -> Result<(), Error> {
+        self.check_concede()?;
+        self.conceded_team = Some(player.team_index());
+        Ok(())
+    }
+
+    /// Checks that a player may concede without modifying the state.
+    pub fn check_concede(&self) -> Result<(), Error> {
+        if self.get_status() == Status::GameOver {
+            return Err(Error::GameOver(Action::Concede));
+        }
+        Ok(())
+    }
+
     /// Handles a player indicating if they approve of their teammates nil bid.
     pub fn on_nil_approval(
         &mut self,
         player: Player,
         is_approved: bool,
-    ) -> Result<(), String> {
-        if let Some(bidding_nil) = self.pending_nil_player {
-            if bidding_nil.teammate() == player {
-                if is_approved {
-                    self.bids[bidding_nil] = Some(Bid::Nil);
-                } else {
-                    self.nil_rejected[bidding_nil] = true;
-                }
-                self.pending_nil_player = None;
-                Ok(())
-            } else {
-                Err("Can not confirm a nil bid, your teammate does not have \
-                a nil bid pending."
-                    .to_string())
-            }
+    ) -> Result<(), Error> {
+        let bidding_nil = self.check_nil_approval(player)?;
+        if is_approved {
+            self.bids[bidding_nil] = Some(Bid::Nil);
         } else {
-            Err("Can not confirm a nil bid, no one has a nil bid pending."
-                .to_string())
+            self.nil_rejected[bidding_nil] = true;
+        }
+        self.pending_nil_player = None;
+        Ok(())
+    }
+
+    /// Checks that a player may approve or reject a nil bid without
+    /// modifying the state.
+    ///
+    /// Returns the player whose nil bid is pending.
+    pub fn check_nil_approval(&self, player: Player) -> Result<Player, Error> {
+        match self.pending_nil_player {
+            Some(bidding_nil) if bidding_nil.teammate() == player => {
+                Ok(bidding_nil)
+            }
+            Some(_) => Err(Error::NoTeammateNilPending),
+            None => Err(Error::NoNilPending),
         }
     }
 }
@@ -523,4 +706,128 @@ mod test {
         // invalid when it is not their turn
         assert!(state.unchecked_on_card_played(Player::Three, card).is_err());
     }
+
+    #[test]
+    fn best_case_remaining() {
+        let mut state = PublicState::default();
+        state.on_bid(Player::Two, Bid::Take(3)).unwrap();
+        state.on_bid(Player::Three, Bid::BlindNil).unwrap();
+
+        // team 0 has not finished bidding
+        assert!(state.best_case_remaining(0).is_none());
+
+        state.on_bid(Player::Four, Bid::Take(4)).unwrap();
+        state.on_bid(Player::One, Bid::Take(5)).unwrap();
+
+        // player four wins the first trick
+        let cards = player::Array::from_array([
+            Card::new(card::Suite::Diamond, card::Value::Number(4)),
+            Card::new(card::Suite::Diamond, card::Value::Number(5)),
+            Card::new(card::Suite::Diamond, card::Value::Number(2)),
+            Card::new(card::Suite::Diamond, card::Value::Ace),
+        ]);
+        for player in Player::Two.iter() {
+            state
+                .unchecked_on_card_played(player, cards[player])
+                .unwrap();
+        }
+
+        let result = state.best_case_remaining(1).unwrap();
+        assert_eq!([Bid::Take(3), Bid::Take(4)], result.bids);
+        assert_eq!([12, 1], result.tricks_taken);
+        assert_eq!(Score::new(7, 6), result.get_score());
+
+        // the remaining tricks go to the player not bidding nil
+        let result = state.best_case_remaining(0).unwrap();
+        assert_eq!([Bid::Take(5), Bid::BlindNil], result.bids);
+        assert_eq!([12, 0], result.tricks_taken);
+        assert_eq!(Score::new(25, 7), result.get_score());
+
+        // there is no third team
+        assert!(state.best_case_remaining(2).is_none());
+    }
+
+    #[test]
+    fn completed_trick() {
+        let mut state = PublicState::default();
+        for player in Player::Two.iter() {
+            state.on_bid(player, Bid::Take(3)).unwrap();
+        }
+        assert!(state.completed_trick(0).is_none());
+
+        // player three wins the first trick
+        let winning_card = Card::new(card::Suite::Club, card::Value::King);
+        let cards = player::Array::from_array([
+            Card::new(card::Suite::Club, card::Value::Number(4)),
+            Card::new(card::Suite::Club, card::Value::Number(5)),
+            winning_card,
+            Card::new(card::Suite::Club, card::Value::Jack),
+        ]);
+        for player in Player::Two.iter() {
+            state
+                .unchecked_on_card_played(player, cards[player])
+                .unwrap();
+        }
+
+        let (trick, player, card) = state.completed_trick(0).unwrap();
+        assert_eq!(Player::Three, player);
+        assert_eq!(winning_card, card);
+        for player in Player::One.iter() {
+            assert_eq!(Some(cards[player]), trick.get_card(player));
+        }
+        assert!(state.completed_trick(1).is_none());
+    }
+
+    #[test]
+    fn bidding_complete() {
+        let mut state = PublicState::default();
+        for player in Player::Two.iter().take(3) {
+            assert!(!state.bidding_complete());
+            state.on_cards_seen(player);
+            state.on_bid(player, Bid::Take(3)).unwrap();
+        }
+        assert!(!state.bidding_complete());
+
+        // the last bid is a nil that needs confirmation
+        state.on_cards_seen(Player::One);
+        state.on_bid(Player::One, Bid::Nil).unwrap();
+        assert!(!state.bidding_complete());
+        state.on_nil_approval(Player::Three, true).unwrap();
+        assert!(state.bidding_complete());
+
+        // a fifth bid is rejected
+        assert!(state.on_bid(Player::Two, Bid::Take(4)).is_err());
+        assert!(state.bidding_complete());
+    }
+
+    #[test]
+    fn cards_played() {
+        let mut state = PublicState::default();
+        for player in Player::Two.iter() {
+            state.on_bid(player, Bid::Take(3)).unwrap();
+        }
+
+        let cards = player::Array::from_array([
+            Card::new(card::Suite::Heart, card::Value::Number(4)),
+            Card::new(card::Suite::Heart, card::Value::Number(9)),
+            Card::new(card::Suite::Heart, card::Value::Queen),
+            Card::new(card::Suite::Club, card::Value::Jack),
+        ]);
+        for player in Player::Two.iter() {
+            state
+                .unchecked_on_card_played(player, cards[player])
+                .unwrap();
+        }
+
+        for player in Player::One.iter() {
+            let expected: card::Set = [cards[player]].iter().collect();
+            assert_eq!(expected, state.get_cards_played_by(player));
+        }
+        assert_eq!(4, state.get_cards_played().len());
+
+        state.restart_round();
+        for player in Player::One.iter() {
+            assert!(state.get_cards_played_by(player).is_empty());
+        }
+    }
 }
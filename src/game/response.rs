@@ -1,13 +1,14 @@
-use crate::card;
+use crate::{card, Error};
 
 /// Sent from a server to a client in response to a `Event` being sent
 /// by a client.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Response {
     /// Response to every event except for SeeCards when no error occurs.
     Ok,
     /// Response to the SeeCards event when no error occurs.
     Cards(card::Set),
     /// Response to any event when an error occurs.
-    Err(String),
+    Err(Error),
 }
@@ -0,0 +1,134 @@
+use super::{Action, Response, State, View};
+use crate::{player, Error, Player};
+
+/// Drives a game by routing events and notifications between a State
+/// and the View of every player.
+///
+/// Useful for tests and simulations where every player is local.
+pub struct Session {
+    /// The state of the game.
+    state: State,
+    /// The view of each player.
+    views: player::Array<View>,
+}
+
+impl Default for Session {
+    /// Creates a new session that uses game::dealer::ShuffledDealer to
+    /// deal cards.
+    fn default() -> Self {
+        Self::new(State::default())
+    }
+}
+
+impl Session {
+    /// Creates a session from a game state.
+    pub fn new(state: State) -> Self {
+        let views = player::Array::from_fn(|player| state.create_view(player));
+        Self { state, views }
+    }
+
+    /// Performs an action as a player.
+    ///
+    /// Any resulting event is handled by the state, the response is given
+    /// to the player's view, and the notification is given to the view
+    /// of every other player.
+    /// If the state rejects the event then the action is undone.
+    pub fn apply(
+        &mut self,
+        player: Player,
+        action: Action,
+    ) -> Result<(), Error> {
+        let event = match self.views[player].perform_action(action)? {
+            Some(event) => event,
+            None => return Ok(()),
+        };
+        let (response, notification) = self.state.handle_event(player, event);
+        if let Response::Err(error) = response {
+            self.views[player].undo_last_local_action()?;
+            return Err(error);
+        }
+        self.views[player].handle_response(response)?;
+        if let Some(notification) = notification {
+            for other_player in player.iter().skip(1) {
+                self.views[other_player]
+                    .handle_notification(notification.clone())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Gets the state of the game.
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    /// Gets a player's view of the game.
+    pub fn view(&self, player: Player) -> &View {
+        &self.views[player]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::card::{self, Suite};
+    use crate::game::dealer::FixedDealer;
+    use crate::game::Status;
+    use crate::{Bid, Score, TeamRoundResult};
+
+    #[test]
+    fn full_round() {
+        // each player is dealt an entire suite, player one holding spades
+        let hands = player::Array::from_fn(|player| {
+            card::Set::suite(Suite::from_index(player.to_index()).unwrap())
+        });
+        let dealer = FixedDealer::new(hands).unwrap();
+        let mut session = Session::new(State::new(Box::new(dealer)));
+
+        for player in Player::Two.iter() {
+            session.apply(player, Action::SeeCards).unwrap();
+            assert_eq!(Some(hands[player]), session.view(player).get_hand());
+            session
+                .apply(player, Action::MakeBid(Bid::Take(3)))
+                .unwrap();
+        }
+        // bids are rejected once bidding is over
+        assert!(session
+            .apply(Player::Two, Action::MakeBid(Bid::Take(3)))
+            .is_err());
+
+        // every player plays their lowest card, so player one trumps the
+        // first trick and then leads spades for the rest of the round
+        for _ in 0..52 {
+            let player = session.state().current_player().unwrap();
+            let card = session.view(player).get_hand().unwrap().lowest();
+            session
+                .apply(player, Action::PlayCard(card.unwrap()))
+                .unwrap();
+        }
+
+        let expected = [
+            TeamRoundResult {
+                bids: [Bid::Take(3), Bid::Take(3)],
+                tricks_taken: [13, 0],
+            },
+            TeamRoundResult {
+                bids: [Bid::Take(3), Bid::Take(3)],
+                tricks_taken: [0, 0],
+            },
+        ];
+        for player in Player::One.iter() {
+            let view = session.view(player);
+            assert_eq!(&vec![expected], view.get_round_results());
+            assert_eq!(
+                [Score::new(6, 7), Score::new(-6, 0)],
+                view.get_scores()
+            );
+            assert_eq!(None, view.get_hand());
+        }
+        assert_eq!(
+            Status::WaitingForBid(Player::Three),
+            session.state().get_status()
+        );
+    }
+}
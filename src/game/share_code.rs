@@ -0,0 +1,197 @@
+//! Encodes the history of a game as a short URL-safe string.
+//!
+//! Each entry is one byte holding a player's index in its top two bits and
+//! what happened in its bottom six bits:
+//! - 0 to 51 is the player playing the card with that index.
+//! - 52 is the player seeing their cards.
+//! - 53 is the player conceding.
+//! - 54 and 55 are the player disapproving and approving of a nil bid.
+//! - 56 is the player making the bid described by the next byte,
+//!   with 0 to 13 taking that many tricks, 14 nil, and 15 blind nil.
+//! - 57 with player one's index is the round being restarted.
+//!
+//! The bytes are then encoded as base64url without padding.
+
+use super::Event;
+use crate::{Bid, Card, Error, Player};
+
+const SEE_CARDS: u8 = 52;
+const CONCEDE: u8 = 53;
+const DISAPPROVE_NIL: u8 = 54;
+const APPROVE_NIL: u8 = 55;
+const MAKE_BID: u8 = 56;
+const RESTART_ROUND: u8 = 57;
+
+const NIL: u8 = 14;
+const BLIND_NIL: u8 = 15;
+
+const ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Something that happened in a game that is needed to rebuild it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(super) enum Entry {
+    /// A valid event handled by State::handle_event().
+    Event(Player, Event),
+    /// The round was restarted by State::restart_round().
+    RestartRound,
+}
+
+/// Encodes a game's history as a share code.
+pub(super) fn encode(history: &[Entry]) -> String {
+    let mut bytes = Vec::with_capacity(history.len());
+    for entry in history {
+        let (player, event) = match *entry {
+            Entry::Event(player, event) => (player, event),
+            Entry::RestartRound => {
+                bytes.push(RESTART_ROUND);
+                continue;
+            }
+        };
+        let player_bits = player.to_index() << 6;
+        match event {
+            Event::PlayCard(card) => bytes.push(player_bits | card.to_index()),
+            Event::SeeCards => bytes.push(player_bits | SEE_CARDS),
+            Event::Concede => bytes.push(player_bits | CONCEDE),
+            Event::ApprovesNil(false) => {
+                bytes.push(player_bits | DISAPPROVE_NIL)
+            }
+            Event::ApprovesNil(true) => bytes.push(player_bits | APPROVE_NIL),
+            Event::MakeBid(bid) => {
+                bytes.push(player_bits | MAKE_BID);
+                bytes.push(match bid {
+                    Bid::Take(tricks) => tricks,
+                    Bid::Nil => NIL,
+                    Bid::BlindNil => BLIND_NIL,
+                });
+            }
+        }
+    }
+    to_base64(&bytes)
+}
+
+/// Decodes a game's history from a share code created by encode().
+pub(super) fn decode(code: &str) -> Result<Vec<Entry>, Error> {
+    let mut bytes = from_base64(code)
+        .ok_or(Error::InvalidShareCode)?
+        .into_iter();
+    let mut history = Vec::new();
+    while let Some(byte) = bytes.next() {
+        if byte == RESTART_ROUND {
+            history.push(Entry::RestartRound);
+            continue;
+        }
+        let player = Player::from_index(byte >> 6)?;
+        let event = match byte & 0x3f {
+            index if index < 52 => Event::PlayCard(Card::from_index(index)?),
+            SEE_CARDS => Event::SeeCards,
+            CONCEDE => Event::Concede,
+            DISAPPROVE_NIL => Event::ApprovesNil(false),
+            APPROVE_NIL => Event::ApprovesNil(true),
+            MAKE_BID => Event::MakeBid(match bytes.next() {
+                Some(NIL) => Bid::Nil,
+                Some(BLIND_NIL) => Bid::BlindNil,
+                Some(tricks) if tricks <= 13 => Bid::Take(tricks),
+                _ => return Err(Error::InvalidShareCode),
+            }),
+            _ => return Err(Error::InvalidShareCode),
+        };
+        history.push(Entry::Event(player, event));
+    }
+    Ok(history)
+}
+
+/// Encodes bytes as base64url without padding.
+fn to_base64(bytes: &[u8]) -> String {
+    let mut code = String::with_capacity(bytes.len() * 4 / 3 + 1);
+    for chunk in bytes.chunks(3) {
+        let bits = chunk
+            .iter()
+            .fold(0u32, |bits, byte| (bits << 8) | *byte as u32)
+            << (8 * (3 - chunk.len()));
+        for i in 0..=chunk.len() {
+            let sextet = (bits >> (18 - 6 * i)) & 0x3f;
+            code.push(ALPHABET[sextet as usize] as char);
+        }
+    }
+    code
+}
+
+/// Decodes base64url without padding.
+///
+/// Returns None if the code is not valid base64url.
+fn from_base64(code: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(code.len() * 3 / 4);
+    for chunk in code.as_bytes().chunks(4) {
+        if chunk.len() == 1 {
+            return None;
+        }
+        let mut bits = 0u32;
+        for c in chunk {
+            let sextet = ALPHABET.iter().position(|a| a == c)? as u32;
+            bits = (bits << 6) | sextet;
+        }
+        bits <<= 6 * (4 - chunk.len());
+        for i in 0..chunk.len() - 1 {
+            bytes.push((bits >> (16 - 8 * i)) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn base64() {
+        assert_eq!("TWFu", to_base64(b"Man"));
+        assert_eq!("TWE", to_base64(b"Ma"));
+        assert_eq!("TQ", to_base64(b"M"));
+        assert_eq!("-_8", to_base64(&[0xfb, 0xff]));
+        assert_eq!("", to_base64(&[]));
+
+        for length in 0..8 {
+            let bytes: Vec<u8> = (0..length).map(|i| 255 - i * 31).collect();
+            assert_eq!(Some(bytes.clone()), from_base64(&to_base64(&bytes)));
+        }
+
+        assert_eq!(None, from_base64("TWFuT"));
+        assert_eq!(None, from_base64("TW=u"));
+    }
+
+    #[test]
+    fn round_trip() {
+        let history = [
+            Entry::Event(Player::Two, Event::MakeBid(Bid::BlindNil)),
+            Entry::Event(Player::Three, Event::SeeCards),
+            Entry::Event(Player::Three, Event::MakeBid(Bid::Nil)),
+            Entry::Event(Player::One, Event::ApprovesNil(false)),
+            Entry::Event(Player::Three, Event::MakeBid(Bid::Take(13))),
+            Entry::RestartRound,
+            Entry::Event(Player::Four, Event::ApprovesNil(true)),
+            Entry::Event(Player::One, Event::PlayCard("SA".parse().unwrap())),
+            Entry::Event(Player::Two, Event::Concede),
+        ];
+        let code = encode(&history);
+        assert_eq!(Ok(history.to_vec()), decode(&code));
+    }
+
+    #[test]
+    fn invalid() {
+        // an unused entry kind
+        assert_eq!(Err(Error::InvalidShareCode), decode(&to_base64(&[60])));
+        // a bid of 16 tricks
+        assert_eq!(
+            Err(Error::InvalidShareCode),
+            decode(&to_base64(&[MAKE_BID, 16]))
+        );
+        // a bid without its value
+        assert_eq!(
+            Err(Error::InvalidShareCode),
+            decode(&to_base64(&[MAKE_BID]))
+        );
+        assert_eq!(Err(Error::InvalidShareCode), decode("not a share code"));
+    }
+}
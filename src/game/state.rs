@@ -1,5 +1,8 @@
-use super::{dealer, Event, Notification, Response, Status, View};
-use crate::{card, player, Player};
+use super::share_code::{self, Entry};
+use super::{
+    dealer, Action, Event, Notification, PlayOutcome, Response, Status, View,
+};
+use crate::{card, player, trick, Error, Player};
 
 /// The state of the game.
 ///
@@ -11,6 +14,105 @@ pub struct State {
     dealer: Box<dyn dealer::Dealer>,
     /// Each player's hands.
     hands: player::Array<card::Set>,
+    /// The events handled by handle_event_at() along with their timestamps.
+    timed_event_log: Vec<(u64, Player, Event)>,
+    /// Every valid event and round restart, used to create share codes.
+    history: Vec<Entry>,
+    /// The number of times the dealer has dealt cards.
+    num_deals: u32,
+}
+
+/// A copy of everything in a State except for its dealer.
+///
+/// Used to persist a game and later resume it with State::restore().
+/// As the dealer is not captured, a seedable dealer should be recreated
+/// from its seed when restoring.
+/// The number of deals already made is recorded so that the recreated
+/// dealer can be advanced to where the original one was.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateSnapshot {
+    /// The state observable to every player.
+    public_state: super::PublicState,
+    /// Each player's hands.
+    hands: player::Array<card::Set>,
+    /// The events handled by handle_event_at() along with their timestamps.
+    timed_event_log: Vec<(u64, Player, Event)>,
+    /// Every valid event and round restart, used to create share codes.
+    history: Vec<Entry>,
+    /// The number of times the dealer has dealt cards.
+    num_deals: u32,
+}
+
+impl StateSnapshot {
+    /// Gets each player's hand.
+    pub fn get_hands(&self) -> player::Array<card::Set> {
+        self.hands
+    }
+
+    /// Gets the status of the game.
+    pub fn get_status(&self) -> Status {
+        self.public_state.get_status()
+    }
+
+    /// Gets the number of the current round, starting at 0.
+    pub fn get_round_number(&self) -> u32 {
+        self.public_state.get_round_number()
+    }
+
+    /// Gets the number of times the dealer had dealt cards.
+    pub fn get_num_deals(&self) -> u32 {
+        self.num_deals
+    }
+
+    /// Gets every valid event handled by State::handle_event_at()
+    /// along with its timestamp, in the order they were handled.
+    pub fn event_log_timed(&self) -> &[(u64, Player, Event)] {
+        &self.timed_event_log
+    }
+
+    /// Checks that the hands fit the cards played this round and that the
+    /// number of deals fits the rounds played.
+    fn validate(&self) -> Result<(), Error> {
+        let mut all = self.public_state.get_cards_played();
+        for hand in self.hands.iter() {
+            if !all.is_disjoint(*hand) {
+                return Err(Error::OverlappingHands);
+            }
+            all |= *hand;
+        }
+        // hands are left as they were when the game ended
+        if self.get_status() != Status::GameOver {
+            for player in Player::One.iter() {
+                let expected =
+                    13 - self.public_state.get_num_cards_played(player);
+                if self.hands[player].len() != expected as usize {
+                    return Err(Error::HandSizeMismatch {
+                        actual: self.hands[player].len(),
+                        expected,
+                    });
+                }
+            }
+        }
+
+        // one deal for the first round and for each round after a
+        // completed or restarted round, unless the game ended
+        let num_restarts = self
+            .history
+            .iter()
+            .filter(|entry| **entry == Entry::RestartRound)
+            .count() as u32;
+        let expected = self.get_round_number() + num_restarts + 1;
+        let ended_after_round = self.get_status() == Status::GameOver
+            && self.num_deals + 1 == expected;
+        if self.num_deals != expected && !ended_after_round {
+            return Err(Error::DealCountMismatch {
+                actual: self.num_deals,
+                expected,
+            });
+        }
+        Ok(())
+    }
 }
 
 impl std::fmt::Debug for State {
@@ -22,6 +124,9 @@ impl std::fmt::Debug for State {
         f.debug_struct("State")
             .field("public_state", &self.public_state)
             .field("hands", &self.hands)
+            .field("timed_event_log", &self.timed_event_log)
+            .field("history", &self.history)
+            .field("num_deals", &self.num_deals)
             .finish()
     }
 }
@@ -41,11 +146,115 @@ impl State {
             public_state: super::PublicState::default(),
             dealer,
             hands: player::Array::default(),
+            timed_event_log: Vec::new(),
+            history: Vec::new(),
+            num_deals: 0,
         };
-        game.hands = game.dealer.deal_cards();
+        game.deal();
         game
     }
 
+    /// Deals new hands to every player.
+    fn deal(&mut self) {
+        self.hands = self.dealer.deal_cards();
+        self.num_deals += 1;
+    }
+
+    /// Creates a new game::State from a boxed dealer and then handles
+    /// a sequence of events in order.
+    ///
+    /// On failure returns the index of the first invalid event and its error.
+    pub fn replay(
+        dealer: Box<dyn dealer::Dealer>,
+        events: &[(Player, Event)],
+    ) -> Result<Self, (usize, Error)> {
+        let mut state = Self::new(dealer);
+        for (index, (player, event)) in events.iter().enumerate() {
+            if let (Response::Err(error), _) =
+                state.handle_event(*player, *event)
+            {
+                return Err((index, error));
+            }
+        }
+        Ok(state)
+    }
+
+    /// Recreates a game::State from a share code created by
+    /// to_share_code() and a boxed dealer.
+    ///
+    /// The dealer must deal the same hands as the dealer of the shared
+    /// game, such as a ShuffledDealer created from the same seed.
+    /// Fails with Error::InvalidShareCode if the code is malformed or if its
+    /// events can not be replayed with the dealer.
+    pub fn from_share_code(
+        code: &str,
+        dealer: Box<dyn dealer::Dealer>,
+    ) -> Result<Self, Error> {
+        let mut state = Self::new(dealer);
+        for entry in share_code::decode(code)? {
+            match entry {
+                Entry::Event(player, event) => {
+                    if let (Response::Err(_), _) =
+                        state.handle_event(player, event)
+                    {
+                        return Err(Error::InvalidShareCode);
+                    }
+                }
+                Entry::RestartRound => state.restart_round(),
+            }
+        }
+        Ok(state)
+    }
+
+    /// Encodes every event handled by this state as a short URL-safe
+    /// string that from_share_code() can rebuild the game from.
+    ///
+    /// The timestamps of events handled by handle_event_at() are not
+    /// included.
+    pub fn to_share_code(&self) -> String {
+        share_code::encode(&self.history)
+    }
+
+    /// Recreates a game::State from a snapshot and a boxed dealer.
+    ///
+    /// The dealer is only used to deal future rounds.
+    /// It should be in the state the original dealer was in when the game
+    /// was created, such as a ShuffledDealer recreated from the same seed,
+    /// and is first advanced past the deals that were already made.
+    ///
+    /// Fails if the snapshot's hands overlap each other or the cards
+    /// played this round, if a hand does not have one card for each trick
+    /// left this round, or if the number of deals does not match the
+    /// rounds played.
+    pub fn restore(
+        snapshot: StateSnapshot,
+        mut dealer: Box<dyn dealer::Dealer>,
+    ) -> Result<Self, Error> {
+        snapshot.validate()?;
+        for _ in 0..snapshot.num_deals {
+            dealer.deal_cards();
+        }
+        Ok(Self {
+            public_state: snapshot.public_state,
+            dealer,
+            hands: snapshot.hands,
+            timed_event_log: snapshot.timed_event_log,
+            history: snapshot.history,
+            num_deals: snapshot.num_deals,
+        })
+    }
+
+    /// Takes a snapshot of everything in this state except for the dealer.
+    pub fn snapshot(&self) -> StateSnapshot {
+        StateSnapshot {
+            public_state: self.public_state.clone(),
+            hands: self.hands,
+            timed_event_log: self.timed_event_log.clone(),
+            history: self.history.clone(),
+            num_deals: self.num_deals,
+        }
+    }
+
     /// Handles an event caused by a player's action.
     ///
     /// Returns a Response that should be sent back to the client sending
@@ -56,7 +265,7 @@ impl State {
         player: Player,
         event: Event,
     ) -> (Response, Option<Notification>) {
-        match event {
+        let (response, notification) = match event {
             Event::SeeCards => {
                 self.public_state.on_cards_seen(player);
                 (
@@ -83,7 +292,7 @@ impl State {
                         self.public_state.get_status()
                     {
                         // start of new round
-                        self.hands = self.dealer.deal_cards();
+                        self.deal();
                     }
                     (Response::Ok, Some(Notification { player, event }))
                 }
@@ -97,7 +306,88 @@ impl State {
                     (Response::Ok, Some(Notification { player, event }))
                 }
             }
+            Event::Concede => {
+                if let Err(error) = self.public_state.on_concede(player) {
+                    (Response::Err(error), None)
+                } else {
+                    (Response::Ok, Some(Notification { player, event }))
+                }
+            }
+        };
+        if !matches!(response, Response::Err(_)) {
+            self.history.push(Entry::Event(player, event));
+        }
+        (response, notification)
+    }
+
+    /// Handles an event caused by a player's action, also returning the
+    /// outcome of the play if the event completed a trick.
+    ///
+    /// Behaves the same as handle_event() otherwise.
+    pub fn handle_event_with_outcome(
+        &mut self,
+        player: Player,
+        event: Event,
+    ) -> (Response, Option<Notification>, Option<PlayOutcome>) {
+        let mut trick = self.public_state.get_trick();
+        let num_rounds = self.public_state.get_round_results().len();
+        let (response, notification) = self.handle_event(player, event);
+        let outcome = match (&response, event) {
+            (Response::Ok, Event::PlayCard(card)) => {
+                // replay the card on a copy of the trick as the state's
+                // trick has already been replaced if this completed it
+                trick.play_card(player, card).ok();
+                match trick.get_status() {
+                    trick::Status::Won(trick_winner, _) => Some(PlayOutcome {
+                        trick_winner,
+                        round_result: self
+                            .public_state
+                            .get_round_results()
+                            .get(num_rounds)
+                            .copied(),
+                    }),
+                    trick::Status::Waiting(_) => None,
+                }
+            }
+            _ => None,
+        };
+        (response, notification, outcome)
+    }
+
+    /// Handles an event caused by a player's action that occurred at
+    /// a given time.
+    ///
+    /// Behaves the same as handle_event(), but also records the event
+    /// along with its timestamp if it was valid.
+    /// The timestamp is not interpreted, but is expected to be
+    /// in milliseconds.
+    pub fn handle_event_at(
+        &mut self,
+        player: Player,
+        event: Event,
+        timestamp: u64,
+    ) -> (Response, Option<Notification>) {
+        let (response, notification) = self.handle_event(player, event);
+        if !matches!(response, Response::Err(_)) {
+            self.timed_event_log.push((timestamp, player, event));
         }
+        (response, notification)
+    }
+
+    /// Gets every valid event handled by handle_event_at()
+    /// along with its timestamp, in the order they were handled.
+    pub fn event_log_timed(&self) -> &[(u64, Player, Event)] {
+        &self.timed_event_log
+    }
+
+    /// Restarts the current round by resetting all bids and plays made this
+    /// round and dealing new hands.
+    ///
+    /// The scores and the results of completed rounds are kept.
+    pub fn restart_round(&mut self) {
+        self.public_state.restart_round();
+        self.deal();
+        self.history.push(Entry::RestartRound);
     }
 
     /// Gets the status of the game.
@@ -105,8 +395,511 @@ impl State {
         self.public_state.get_status()
     }
 
+    /// Gets the player that the game is waiting on.
+    ///
+    /// Returns None if the game is over.
+    pub fn current_player(&self) -> Option<Player> {
+        match self.get_status() {
+            Status::WaitingForBid(player)
+            | Status::WaitingForNilConfirmation(player)
+            | Status::WaitingForPlay(player) => Some(player),
+            Status::GameOver => None,
+        }
+    }
+
+    /// Gets if the game is over.
+    pub fn is_game_over(&self) -> bool {
+        self.get_status() == Status::GameOver
+    }
+
     /// Creates a player's view of the game.
     pub fn create_view(&self, player: Player) -> View {
         View::from_public_state(player, &self.public_state, self.hands[player])
     }
+
+    /// Gets the actions every player is currently allowed to perform.
+    ///
+    /// The order of the actions for each player is unspecified.
+    pub fn allowed_actions_all(&self) -> player::Array<Vec<Action>> {
+        let mut actions = player::Array::<Vec<Action>>::default();
+        for player in Player::One.iter() {
+            actions[player] = self
+                .create_view(player)
+                .get_allowed_actions()
+                .into_iter()
+                .collect();
+        }
+        actions
+    }
+}
+
+/// Checks that a log of events is valid by replaying it on a new game
+/// whose hands are dealt by a ShuffledDealer seeded with `seed`.
+///
+/// On failure returns the index of the first invalid event and its error.
+pub fn validate_log(
+    seed: u64,
+    events: &[(Player, Event)],
+) -> Result<(), (usize, Error)> {
+    use rand::SeedableRng;
+
+    let dealer = dealer::ShuffledDealer::with_rng(
+        rand::rngs::StdRng::seed_from_u64(seed),
+    );
+    State::replay(Box::new(dealer), events).map(|_| ())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Bid, Score};
+
+    #[test]
+    fn restart_round() {
+        let mut state = State::default();
+        let first_bidder = Player::Two;
+
+        // everyone bids
+        for player in first_bidder.iter() {
+            state.handle_event(player, Event::SeeCards);
+            let (response, _) =
+                state.handle_event(player, Event::MakeBid(Bid::Take(3)));
+            assert!(matches!(response, Response::Ok));
+        }
+
+        // the first bidder plays a card
+        let card = state
+            .create_view(first_bidder)
+            .get_allowed_actions()
+            .iter()
+            .find_map(|action| match action {
+                Action::PlayCard(card) => Some(*card),
+                _ => None,
+            })
+            .unwrap();
+        let (response, _) =
+            state.handle_event(first_bidder, Event::PlayCard(card));
+        assert!(matches!(response, Response::Ok));
+
+        state.restart_round();
+
+        assert_eq!(Status::WaitingForBid(first_bidder), state.get_status());
+        for player in Player::One.iter() {
+            assert_eq!(13, state.hands[player].len());
+            assert_eq!(None, state.create_view(player).get_bid(player));
+            assert!(!state.create_view(player).can_see_cards(player));
+        }
+        assert_eq!(
+            [Score::default(), Score::default()],
+            state.create_view(Player::One).get_scores()
+        );
+    }
+
+    #[test]
+    fn timed_event_log() {
+        let mut state = State::default();
+        state.handle_event_at(Player::Two, Event::SeeCards, 100);
+        state.handle_event_at(Player::Two, Event::MakeBid(Bid::Take(3)), 250);
+        // invalid, not their turn to bid
+        state.handle_event_at(Player::Four, Event::MakeBid(Bid::Take(3)), 300);
+        state.handle_event_at(Player::Four, Event::SeeCards, 400);
+
+        assert_eq!(
+            &[
+                (100, Player::Two, Event::SeeCards),
+                (250, Player::Two, Event::MakeBid(Bid::Take(3))),
+                (400, Player::Four, Event::SeeCards),
+            ],
+            state.event_log_timed()
+        );
+    }
+
+    #[test]
+    fn allowed_actions_all() {
+        use std::collections::HashSet;
+
+        let state = State::default();
+        let actions = state.allowed_actions_all();
+        for player in Player::One.iter() {
+            let expected: HashSet<Action> = if player == Player::Two {
+                [Action::SeeCards, Action::MakeBid(Bid::BlindNil)]
+            } else {
+                [Action::SeeCards, Action::Wait]
+            }
+            .iter()
+            .copied()
+            .collect();
+            let actual: HashSet<Action> =
+                actions[player].iter().copied().collect();
+            assert_eq!(2, actions[player].len());
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn concede() {
+        let mut state = State::default();
+        let (response, notification) =
+            state.handle_event(Player::Four, Event::Concede);
+        assert_eq!(Response::Ok, response);
+        assert_eq!(
+            Some(Notification {
+                player: Player::Four,
+                event: Event::Concede,
+            }),
+            notification
+        );
+        assert_eq!(Status::GameOver, state.get_status());
+        assert_eq!(
+            Some(([Score::default(), Score::default()], 0)),
+            state.create_view(Player::One).final_standings()
+        );
+
+        // can not concede a finished game
+        let (response, _) = state.handle_event(Player::One, Event::Concede);
+        assert!(matches!(response, Response::Err(_)));
+    }
+
+    #[test]
+    fn is_game_over() {
+        let mut state = State::default();
+        assert!(!state.is_game_over());
+
+        state.handle_event(Player::Two, Event::Concede);
+        assert!(state.is_game_over());
+    }
+
+    #[test]
+    fn validate_log() {
+        let mut events = Vec::new();
+        for player in Player::Two.iter() {
+            events.push((player, Event::SeeCards));
+            events.push((player, Event::MakeBid(Bid::Take(3))));
+        }
+        assert_eq!(Ok(()), super::validate_log(7, &events));
+
+        // player four bids out of turn
+        events.insert(2, (Player::Four, Event::MakeBid(Bid::Take(3))));
+        assert_eq!(
+            Err((2, Error::NotYourTurnToBid)),
+            super::validate_log(7, &events)
+        );
+    }
+
+    #[test]
+    fn handle_event_with_outcome() {
+        let mut state = State::default();
+        for player in Player::Two.iter() {
+            state.handle_event(player, Event::SeeCards);
+            let (response, _, outcome) = state.handle_event_with_outcome(
+                player,
+                Event::MakeBid(Bid::Take(3)),
+            );
+            assert_eq!(Response::Ok, response);
+            assert_eq!(None, outcome);
+        }
+
+        for trick_index in 0..13 {
+            let mut trick = state.create_view(Player::One).get_trick();
+            for play_index in 0..4 {
+                let player = match state.get_status() {
+                    Status::WaitingForPlay(player) => player,
+                    status => panic!("Unexpected status {:?}", status),
+                };
+                let card = state
+                    .create_view(player)
+                    .get_allowed_actions()
+                    .iter()
+                    .find_map(|action| match action {
+                        Action::PlayCard(card) => Some(*card),
+                        _ => None,
+                    })
+                    .unwrap();
+                trick.play_card(player, card).unwrap();
+                let (response, _, outcome) = state
+                    .handle_event_with_outcome(player, Event::PlayCard(card));
+                assert_eq!(Response::Ok, response);
+
+                if play_index < 3 {
+                    assert_eq!(None, outcome);
+                    continue;
+                }
+                let outcome = outcome.unwrap();
+                assert!(matches!(
+                    trick.get_status(),
+                    trick::Status::Won(winner, _) if winner == outcome.trick_winner
+                ));
+                if trick_index < 12 {
+                    assert_eq!(None, outcome.round_result);
+                } else {
+                    // the round ending play reports the round's result
+                    assert_eq!(
+                        state
+                            .create_view(Player::One)
+                            .get_round_results()
+                            .last()
+                            .copied(),
+                        outcome.round_result
+                    );
+                    assert!(outcome.round_result.is_some());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn current_player() {
+        let mut state = State::default();
+        // player one deals, so player two bids first
+        assert_eq!(Some(Player::Two), state.current_player());
+
+        state.handle_event(Player::Two, Event::MakeBid(Bid::BlindNil));
+        assert_eq!(Some(Player::Three), state.current_player());
+
+        state.handle_event(Player::One, Event::Concede);
+        assert_eq!(None, state.current_player());
+    }
+
+    #[test]
+    fn snapshot_and_restore() {
+        let mut state = State::default();
+        for player in Player::Two.iter() {
+            state.handle_event(player, Event::SeeCards);
+            state.handle_event_at(player, Event::MakeBid(Bid::Take(3)), 10);
+        }
+        let card = state.create_view(Player::Two).get_hand().unwrap().lowest();
+        state.handle_event(Player::Two, Event::PlayCard(card.unwrap()));
+
+        let snapshot = state.snapshot();
+        let mut restored = State::restore(
+            snapshot.clone(),
+            Box::new(dealer::ShuffledDealer::default()),
+        )
+        .unwrap();
+        assert_eq!(snapshot, restored.snapshot());
+        assert_eq!(state.hands, restored.hands);
+        assert_eq!(state.get_status(), restored.get_status());
+
+        // both continue identically
+        let player = Player::Three;
+        let card = state.create_view(player).get_hand().unwrap().highest();
+        assert_eq!(
+            state.handle_event(player, Event::PlayCard(card.unwrap())),
+            restored.handle_event(player, Event::PlayCard(card.unwrap()))
+        );
+        assert_eq!(state.snapshot(), restored.snapshot());
+    }
+
+    #[test]
+    fn snapshot_getters() {
+        let mut state = State::default();
+        state.handle_event_at(Player::Two, Event::SeeCards, 10);
+        state.restart_round();
+
+        let snapshot = state.snapshot();
+        assert_eq!(state.hands, snapshot.get_hands());
+        assert_eq!(Status::WaitingForBid(Player::Two), snapshot.get_status());
+        assert_eq!(0, snapshot.get_round_number());
+        assert_eq!(2, snapshot.get_num_deals());
+        assert_eq!(
+            &[(10, Player::Two, Event::SeeCards)],
+            snapshot.event_log_timed()
+        );
+    }
+
+    #[test]
+    fn restore_invalid() {
+        let mut state = State::default();
+        for player in Player::Two.iter() {
+            state.handle_event(player, Event::SeeCards);
+            state.handle_event(player, Event::MakeBid(Bid::Take(3)));
+        }
+        let card = state
+            .public_state
+            .get_trick()
+            .get_playable_cards(state.hands[Player::Two], false)
+            .lowest()
+            .unwrap();
+        let (response, _) =
+            state.handle_event(Player::Two, Event::PlayCard(card));
+        assert_eq!(Response::Ok, response);
+        let restore = |snapshot: StateSnapshot| {
+            State::restore(
+                snapshot,
+                Box::new(dealer::ShuffledDealer::default()),
+            )
+            .map(|_| ())
+        };
+
+        // a card in two hands
+        let mut snapshot = state.snapshot();
+        let card = snapshot.hands[Player::Three].lowest().unwrap();
+        snapshot.hands[Player::One].insert(card);
+        assert_eq!(Err(Error::OverlappingHands), restore(snapshot));
+
+        // a card that was played still in a hand
+        let mut snapshot = state.snapshot();
+        snapshot.hands[Player::One].insert(card);
+        assert_eq!(Err(Error::OverlappingHands), restore(snapshot));
+
+        // a card missing from a hand
+        let mut snapshot = state.snapshot();
+        let card = snapshot.hands[Player::Two].lowest().unwrap();
+        snapshot.hands[Player::Two].remove(card);
+        assert_eq!(
+            Err(Error::HandSizeMismatch {
+                actual: 11,
+                expected: 12
+            }),
+            restore(snapshot)
+        );
+
+        // a deal for a round that was never played
+        let mut snapshot = state.snapshot();
+        snapshot.num_deals = 2;
+        assert_eq!(
+            Err(Error::DealCountMismatch {
+                actual: 2,
+                expected: 1
+            }),
+            restore(snapshot)
+        );
+    }
+
+    #[test]
+    fn restore_finished_game() {
+        use crate::game::ai::{HeuristicStrategy, Strategy};
+
+        let mut state = State::default();
+        let mut strategy = HeuristicStrategy::default();
+        while let Some(player) = state.current_player() {
+            let mut view = state.create_view(player);
+            let action = strategy.choose_action(&view);
+            let event = view.perform_action(action).unwrap().unwrap();
+            state.handle_event(player, event);
+        }
+        let restored = State::restore(
+            state.snapshot(),
+            Box::new(dealer::ShuffledDealer::default()),
+        )
+        .unwrap();
+        assert_eq!(state.snapshot(), restored.snapshot());
+
+        // a game conceded mid-round
+        let mut state = State::default();
+        state.handle_event(Player::Three, Event::Concede);
+        assert!(State::restore(
+            state.snapshot(),
+            Box::new(dealer::ShuffledDealer::default())
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn restore_seeded_dealer() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let make_dealer = || {
+            Box::new(dealer::ShuffledDealer::with_rng(StdRng::seed_from_u64(7)))
+        };
+        let mut state = State::new(make_dealer());
+        let first_hands = state.hands;
+        state.restart_round();
+
+        let mut restored =
+            State::restore(state.snapshot(), make_dealer()).unwrap();
+        assert_eq!(state.hands, restored.hands);
+
+        // the restored dealer continues where the original left off
+        state.restart_round();
+        restored.restart_round();
+        assert_eq!(state.hands, restored.hands);
+        assert_ne!(first_hands, restored.hands);
+        assert_eq!(state.snapshot(), restored.snapshot());
+    }
+
+    #[test]
+    fn replay() {
+        use crate::game::ai::{HeuristicStrategy, Strategy};
+
+        let hands = player::Array::from_fn(|player| {
+            card::Set::suite(
+                card::Suite::from_index(player.to_index()).unwrap(),
+            )
+        });
+        let make_dealer = || Box::new(dealer::FixedDealer::new(hands).unwrap());
+
+        // play a round, recording every event
+        let mut state = State::new(make_dealer());
+        let mut strategy = HeuristicStrategy::default();
+        let mut events = Vec::new();
+        while state.create_view(Player::One).get_round_number() == 0 {
+            let player = state.current_player().unwrap();
+            let mut view = state.create_view(player);
+            let action = strategy.choose_action(&view);
+            let event = view.perform_action(action).unwrap().unwrap();
+            let (response, _) = state.handle_event(player, event);
+            assert!(!matches!(response, Response::Err(_)));
+            events.push((player, event));
+        }
+
+        let replayed = State::replay(make_dealer(), &events).unwrap();
+        assert_eq!(state.snapshot(), replayed.snapshot());
+        assert_eq!(
+            state.create_view(Player::One).get_scores(),
+            replayed.create_view(Player::One).get_scores()
+        );
+
+        // replaying stops at the first invalid event
+        events.insert(3, (Player::Two, Event::MakeBid(Bid::Take(1))));
+        assert!(matches!(State::replay(make_dealer(), &events), Err((3, _))));
+    }
+
+    #[test]
+    fn share_code() {
+        use rand::SeedableRng;
+
+        let make_dealer = || {
+            Box::new(dealer::ShuffledDealer::with_rng(
+                rand::rngs::StdRng::seed_from_u64(7),
+            ))
+        };
+
+        // a restarted round and part of the next one
+        let mut state = State::new(make_dealer());
+        state.handle_event(Player::Two, Event::MakeBid(Bid::BlindNil));
+        state.restart_round();
+        for player in Player::Two.iter() {
+            state.handle_event(player, Event::SeeCards);
+        }
+        state.handle_event(Player::Two, Event::MakeBid(Bid::Nil));
+        state.handle_event(Player::Four, Event::ApprovesNil(true));
+        for player in [Player::Three, Player::Four, Player::One].iter() {
+            state.handle_event(*player, Event::MakeBid(Bid::Take(3)));
+        }
+        for player in [Player::Two, Player::Three].iter() {
+            let view = state.create_view(*player);
+            let card = view
+                .get_trick()
+                .get_playable_cards(view.get_hand().unwrap(), false)
+                .lowest();
+            state.handle_event(*player, Event::PlayCard(card.unwrap()));
+        }
+        assert_eq!(Status::WaitingForPlay(Player::Four), state.get_status());
+
+        let code = state.to_share_code();
+        assert!(code
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        let shared = State::from_share_code(&code, make_dealer()).unwrap();
+        assert_eq!(state.snapshot(), shared.snapshot());
+
+        // the code can not be replayed with a different deal
+        assert_eq!(
+            Err(Error::InvalidShareCode),
+            State::from_share_code(&code, Box::new(dealer::CutDealer::new(0)))
+                .map(|_| ())
+        );
+    }
 }
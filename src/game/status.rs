@@ -2,6 +2,7 @@ use crate::Player;
 
 /// The status of the game.
 #[derive(Eq, PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Status {
     /// The game is waiting for a player to bid.
     WaitingForBid(Player),
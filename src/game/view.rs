@@ -1,5 +1,10 @@
-use super::{Action, Event, Notification, PublicState, Response, Status};
-use crate::{card, scoring, Bid, Card, Player, Score, TeamRoundResult, Trick};
+use super::{
+    Action, Event, Notification, Phase, PublicState, Response, Status,
+};
+use crate::{
+    card, player, scoring, Bid, Card, Error, Player, Score, TeamRoundResult,
+    Trick,
+};
 
 /// A player's view of the state of the game.
 ///
@@ -8,11 +13,18 @@ use crate::{card, scoring, Bid, Card, Player, Score, TeamRoundResult, Trick};
 #[derive(Clone, Debug)]
 pub struct View {
     /// The player whose view this is of the game.
+    ///
+    /// Is Player::One for a spectator.
     player: Player,
+    /// If this view is of a spectator that is not playing the game.
+    spectator: bool,
     /// The public game state.
     public_state: PublicState,
     /// The user's hand, if they have selected seen cards.
     hand: Option<card::Set>,
+    /// The public state and hand from before the last action performed
+    /// that has not yet been acknowledged by the server.
+    undo_snapshot: Option<(PublicState, Option<card::Set>)>,
 }
 
 impl View {
@@ -25,12 +37,14 @@ impl View {
     ) -> Self {
         View {
             player,
+            spectator: false,
             public_state: public_state.clone(),
             hand: if public_state.can_see_cards(player) {
                 Some(hand)
             } else {
                 None
             },
+            undo_snapshot: None,
         }
     }
 
@@ -38,8 +52,21 @@ impl View {
     pub fn new(player: Player) -> Self {
         View {
             player,
+            spectator: false,
             public_state: PublicState::default(),
             hand: None,
+            undo_snapshot: None,
+        }
+    }
+
+    /// Creates a view of a brand new game for a spectator.
+    ///
+    /// A spectator has no hand, can not perform any actions, and
+    /// applies the notifications from every player.
+    pub fn spectator() -> Self {
+        View {
+            spectator: true,
+            ..Self::new(Player::One)
         }
     }
 }
@@ -51,11 +78,110 @@ impl View {
         self.public_state.get_scores()
     }
 
+    /// Gets the index of the team that won the game.
+    ///
+    /// Returns None if the game is not over.
+    pub fn get_winning_team(&self) -> Option<u8> {
+        self.public_state.get_winning_team_index()
+    }
+
+    /// Gets the final scores and the index of the winning team.
+    ///
+    /// Returns None if the game is not over.
+    pub fn final_standings(&self) -> Option<([Score; 2], usize)> {
+        let winner = self.get_winning_team()?;
+        Some((self.get_scores(), winner as usize))
+    }
+
+    /// Gets if a team's lead is large enough that they can not lose
+    /// the game at the end of this round under a set of rules.
+    ///
+    /// This is the case when the lead is larger than the most that
+    /// a single round can change the difference in scores by.
+    /// Fails if the team index is invalid.
+    pub fn is_lead_safe(
+        &self,
+        team: u8,
+        rules: &scoring::ScoringRules,
+    ) -> Result<bool, Error> {
+        player::team_members(team)?;
+        let scores = self.get_scores();
+        let lead = scores[team as usize].get_tens()
+            - scores[1 - team as usize].get_tens();
+        Ok(lead > scoring::max_round_swing_with_rules(rules))
+    }
+
+    /// Gets if a team is certain to lose the game at the end of this round.
+    ///
+    /// This is the case when the opposing team wins even if this team
+    /// takes every remaining trick this round.
+    /// Returns false if either team has not finished bidding.
+    /// Fails if the team index is invalid.
+    ///
+    /// This does not end the game, the round is still played out.
+    pub fn is_eliminated(&self, team: u8) -> Result<bool, Error> {
+        let members = player::team_members(team)?;
+        let opponent = 1 - team;
+        // the player after each team member is an opponent
+        let opponents = [members[0].next(), members[1].next()];
+        let best_case = match self.best_case_remaining(team) {
+            Some(result) => result,
+            None => return Ok(false),
+        };
+        let opponent_bids =
+            match (self.get_bid(opponents[0]), self.get_bid(opponents[1])) {
+                (Some(bid1), Some(bid2)) => [bid1, bid2],
+                _ => return Ok(false),
+            };
+        let opponent_result = TeamRoundResult {
+            bids: opponent_bids,
+            tricks_taken: [
+                self.get_num_tricks(opponents[0]),
+                self.get_num_tricks(opponents[1]),
+            ],
+        };
+
+        let mut scores = self.get_scores();
+        scores[team as usize] += best_case.get_score();
+        scores[opponent as usize] += opponent_result.get_score();
+        Ok(scoring::get_winning_team_index(scores) == Some(opponent))
+    }
+
     /// Get the results of all completed rounds.
     pub fn get_round_results(&self) -> &Vec<[TeamRoundResult; 2]> {
         self.public_state.get_round_results()
     }
 
+    /// Gets the number of the current round, starting at 0.
+    pub fn get_round_number(&self) -> u32 {
+        self.public_state.get_round_number()
+    }
+
+    /// Gets the dealer of the current round.
+    pub fn get_dealer(&self) -> Player {
+        self.public_state.get_dealer()
+    }
+
+    /// Gets the first player to bid in the current round,
+    /// the player after the dealer.
+    pub fn get_first_bidder(&self) -> Player {
+        self.public_state.get_first_bidder()
+    }
+
+    /// Gets the completed rounds, each with its index starting at 0, the
+    /// results of each team, and the scores after that round.
+    pub fn round_history(
+        &self,
+    ) -> impl Iterator<Item = (usize, [TeamRoundResult; 2], [Score; 2])> + '_
+    {
+        let round_results = self.get_round_results();
+        round_results
+            .iter()
+            .zip(scoring::cumulative_scores(round_results))
+            .enumerate()
+            .map(|(index, (results, scores))| (index, *results, scores))
+    }
+
     /// Gets if a player can see their cards.
     pub fn can_see_cards(&self, player: Player) -> bool {
         self.public_state.can_see_cards(player)
@@ -79,11 +205,104 @@ impl View {
         self.public_state.get_bid(player)
     }
 
+    /// Gets if every player has bid this round and no nil bid
+    /// is waiting for confirmation.
+    pub fn bidding_complete(&self) -> bool {
+        self.public_state.bidding_complete()
+    }
+
     /// Gets the number of tricks that a player has taken.
     pub fn get_num_tricks(&self, player: Player) -> u8 {
         self.public_state.get_num_tricks(player)
     }
 
+    /// Gets a team's result for this round if they take every remaining
+    /// trick.
+    ///
+    /// Returns None if either player on the team has not bid yet, as a
+    /// result can not be scored without both bids, or if the team index
+    /// is not 0 or 1.
+    pub fn best_case_remaining(&self, team: u8) -> Option<TeamRoundResult> {
+        self.public_state.best_case_remaining(team)
+    }
+
+    /// Gets a trick completed this round along with the player and card
+    /// that won it.
+    ///
+    /// Returns None if fewer than `index + 1` tricks have been
+    /// completed this round.
+    pub fn completed_trick(
+        &self,
+        index: usize,
+    ) -> Option<(Trick, Player, Card)> {
+        self.public_state.completed_trick(index)
+    }
+
+    /// Gets the cards that have not been played this round and are not
+    /// in this player's hand.
+    pub fn get_unseen_cards(&self) -> card::Set {
+        !self.public_state.get_cards_played() - self.hand.unwrap_or_default()
+    }
+
+    /// Gets the cards a player has played this round.
+    pub fn get_cards_played_by(&self, player: Player) -> card::Set {
+        self.public_state.get_cards_played_by(player)
+    }
+
+    /// Gets the cards this view's player has played this round.
+    ///
+    /// This is based on the tricks played this round, so is available
+    /// even if this player has not seen their cards.
+    /// Is empty for a spectator.
+    pub fn my_played_cards(&self) -> card::Set {
+        if self.spectator {
+            return card::Set::default();
+        }
+        self.get_cards_played_by(self.player)
+    }
+
+    /// Gets the suites each player is known to be void in, indexed by
+    /// card::Suite::to_index().
+    pub fn inferred_voids(&self) -> player::Array<[bool; 4]> {
+        self.public_state.inferred_voids()
+    }
+
+    /// Gets if a player is known to be void in a suite, because they did
+    /// not follow it when it was led this round.
+    pub fn is_known_void(&self, player: Player, suite: card::Suite) -> bool {
+        self.inferred_voids()[player][suite.to_index() as usize]
+    }
+
+    /// Gets the cards that a player could still be holding.
+    ///
+    /// For this view's player this is their hand if they have seen it.
+    /// Otherwise this is every unseen card that is not in a suite the
+    /// player is known to be void in.
+    pub fn possible_cards(&self, player: Player) -> card::Set {
+        if player == self.player {
+            if let Some(hand) = self.hand {
+                return hand;
+            }
+        }
+        card::Suite::all()
+            .filter(|suite| self.is_known_void(player, *suite))
+            .fold(self.get_unseen_cards(), |cards, suite| {
+                cards - card::Set::suite(suite)
+            })
+    }
+
+    /// Gets the other players that are known to be void in a suite.
+    ///
+    /// For a spectator this includes every player.
+    pub fn players_void_in(&self, suite: card::Suite) -> Vec<Player> {
+        let voids = self.inferred_voids();
+        self.player
+            .iter()
+            .skip(if self.spectator { 0 } else { 1 })
+            .filter(|player| voids[*player][suite.to_index() as usize])
+            .collect()
+    }
+
     /// Gets the a copy of the active trick.
     ///
     /// This contains the cards that have been played by each player.
@@ -96,11 +315,29 @@ impl View {
         self.public_state.get_status()
     }
 
+    /// Gets the phase of this game.
+    pub fn phase(&self) -> Phase {
+        match self.get_status() {
+            Status::WaitingForBid(_) | Status::WaitingForNilConfirmation(_) => {
+                Phase::Bidding
+            }
+            Status::WaitingForPlay(_) => Phase::Playing,
+            Status::GameOver => Phase::GameOver,
+        }
+    }
+
     /// Gets the player that this view is for.
+    ///
+    /// Is Player::One for a spectator.
     pub fn get_player(&self) -> Player {
         self.player
     }
 
+    /// Gets if this view is for a spectator rather than a player.
+    pub fn is_spectator(&self) -> bool {
+        self.spectator
+    }
+
     /// Gets the hand of this player.
     ///
     /// Returns None if the game is over or if the player has
@@ -108,6 +345,161 @@ impl View {
     pub fn get_hand(&self) -> Option<card::Set> {
         self.hand
     }
+
+    /// Gets the number of cards remaining in each player's hand.
+    pub fn hand_sizes(&self) -> player::Array<u8> {
+        let mut sizes = player::Array::from_value(&13);
+        for player in Player::One.iter() {
+            sizes[player] -= self.public_state.get_num_cards_played(player);
+        }
+        sizes
+    }
+
+    /// Gets the only card that this player may play, if it is their turn
+    /// to play and they have exactly one legal play.
+    pub fn forced_play(&self) -> Option<Card> {
+        if self.get_status() != Status::WaitingForPlay(self.player) {
+            return None;
+        }
+        let playable = self
+            .get_trick()
+            .get_playable_cards(self.hand?, self.is_trump_broken());
+        if playable.len() == 1 {
+            playable.iter().next()
+        } else {
+            None
+        }
+    }
+
+    /// Gets the number of cards that this player may play.
+    ///
+    /// Is 0 if it is not this player's turn to play or if their hand
+    /// is not known.
+    pub fn num_legal_plays(&self) -> u8 {
+        match self.hand {
+            Some(hand)
+                if self.get_status() == Status::WaitingForPlay(self.player) =>
+            {
+                self.get_trick()
+                    .get_playable_cards(hand, self.is_trump_broken())
+                    .len() as u8
+            }
+            _ => 0,
+        }
+    }
+
+    /// Gets if this player can follow the suite that was led.
+    ///
+    /// Returns None if it is not this player's turn to play,
+    /// if this player is leading, or if this player's hand is not known.
+    pub fn can_follow_suit(&self) -> Option<bool> {
+        if self.get_status() != Status::WaitingForPlay(self.player) {
+            return None;
+        }
+        let suite = self.get_trick().get_suite()?;
+        Some(!self.hand?.of_suite(suite).is_empty())
+    }
+
+    /// Gets the cards that this player may play that would currently
+    /// win the active trick.
+    ///
+    /// Is empty if it is not this player's turn to play.
+    pub fn winning_plays(&self) -> card::Set {
+        let hand = match self.hand {
+            Some(hand) => hand,
+            None => return card::Set::default(),
+        };
+        if self.get_status() != Status::WaitingForPlay(self.player) {
+            return card::Set::default();
+        }
+        let trick = self.get_trick();
+        trick
+            .get_playable_cards(hand, self.is_trump_broken())
+            .iter()
+            .filter(|card| {
+                let mut trick = trick;
+                trick.play_card(self.player, *card).is_ok()
+                    && trick.current_winner().map(|(player, _)| player)
+                        == Some(self.player)
+            })
+            .collect()
+    }
+
+    /// Gets if this player's team could still win the active trick.
+    ///
+    /// This is true if the team is currently winning or if a player on the
+    /// team that has not yet played could hold a card that beats the
+    /// current winner.
+    /// The cards a player could hold are given by possible_cards(), so
+    /// suites the teammate is known to be void in are ruled out.
+    /// Returns None if no one is waiting to play a card or if this view
+    /// is for a spectator.
+    pub fn team_can_still_win_trick(&self) -> Option<bool> {
+        if self.spectator
+            || !matches!(self.get_status(), Status::WaitingForPlay(_))
+        {
+            return None;
+        }
+        let trick = self.get_trick();
+        let winner = match trick.current_winner() {
+            Some((winner, _)) => winner,
+            None => return Some(true),
+        };
+        if winner == self.player || winner == self.player.teammate() {
+            return Some(true);
+        }
+
+        for player in [self.player, self.player.teammate()].iter() {
+            if trick.get_card(*player).is_some() {
+                continue;
+            }
+            let possible = self.possible_cards(*player);
+            let playable = match self.hand {
+                Some(_) if *player == self.player => {
+                    trick.get_playable_cards(possible, self.is_trump_broken())
+                }
+                // a hidden hand may be void in the led suite, so any card
+                // it could hold may be playable
+                _ => possible,
+            };
+            if playable.iter().any(|card| trick.would_win(card)) {
+                return Some(true);
+            }
+        }
+        Some(false)
+    }
+
+    /// Gets if this player may lead a spade.
+    ///
+    /// This is only true when it is this player's turn to lead and either
+    /// trump is broken or their hand contains only spades.
+    pub fn can_lead_spades(&self) -> bool {
+        let is_lead = !self.spectator
+            && self.get_status() == Status::WaitingForPlay(self.player)
+            && self.get_trick().get_suite().is_none();
+        let spades = card::Set::suite(card::Suite::Spade);
+        let only_spades =
+            matches!(self.hand, Some(hand) if (hand - spades).is_empty());
+        is_lead && (self.is_trump_broken() || only_spades)
+    }
+
+    /// Checks that the size of this player's hand matches the number of
+    /// cards they have played this round.
+    ///
+    /// Does nothing if the player can not see their hand.
+    pub fn validate_hand_consistency(&self) -> Result<(), Error> {
+        if let Some(hand) = self.hand {
+            let expected =
+                13 - self.public_state.get_num_cards_played(self.player);
+            if hand.len() != expected as usize {
+                return Err(Error::HandSizeMismatch {
+                    actual: hand.len(),
+                    expected,
+                });
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Manipulates the game through Actions, Notifications, and Responses.
@@ -122,21 +514,13 @@ impl View {
     }
 
     /// Makes a bid as the player.
-    fn make_bid(&mut self, bid: Bid) -> Result<Event, String> {
-        self.public_state.on_bid(self.player, bid)?;
-        Ok(Event::MakeBid(bid))
-    }
-
-    /// Approves this player's teammate's nil bid.
-    fn approve_nil(&mut self) -> Result<Event, String> {
-        self.public_state.on_nil_approval(self.player, true)?;
-        Ok(Event::ApprovesNil(true))
+    fn make_bid(&mut self, bid: Bid) -> Result<(), Error> {
+        self.public_state.on_bid(self.player, bid)
     }
 
-    /// Rejects this player's teammate's nil bid.
-    fn reject_nil(&mut self) -> Result<Event, String> {
-        self.public_state.on_nil_approval(self.player, false)?;
-        Ok(Event::ApprovesNil(false))
+    /// Approves or rejects this player's teammate's nil bid.
+    fn approve_nil(&mut self, is_approved: bool) -> Result<(), Error> {
+        self.public_state.on_nil_approval(self.player, is_approved)
     }
 
     /// Internal function called after a card is played.
@@ -149,21 +533,75 @@ impl View {
     }
 
     /// Plays a card as this player.
-    fn play_card(&mut self, card: Card) -> Result<Event, String> {
+    fn play_card(&mut self, card: Card) -> Result<(), Error> {
         self.public_state.on_card_played(
             self.player,
             card,
-            self.hand.as_mut().ok_or_else(|| {
-                "Can not play a card without seeing your hand."
-            })?,
+            self.hand.as_mut().ok_or(Error::HandNotSeen)?,
         )?;
         self.after_card_played();
-        Ok(Event::PlayCard(card))
+        Ok(())
+    }
+
+    /// Checks that this player may perform an action without modifying
+    /// the view.
+    ///
+    /// Returns the event that performing the action creates, if any.
+    fn check_action(&self, action: Action) -> Result<Option<Event>, Error> {
+        match action {
+            Action::Wait => {
+                let player = match self.get_status() {
+                    Status::WaitingForBid(player) => player,
+                    Status::WaitingForNilConfirmation(player) => player,
+                    Status::WaitingForPlay(player) => player,
+                    Status::GameOver => {
+                        return Err(Error::GameOver(action));
+                    }
+                };
+                if self.player == player {
+                    Err(Error::WaitingOnYou)
+                } else {
+                    Ok(None)
+                }
+            }
+            Action::SeeCards => {
+                if self.get_status() == Status::GameOver {
+                    Err(Error::GameOver(action))
+                } else if self.public_state.can_see_cards(self.player) {
+                    Err(Error::HandAlreadySeen)
+                } else {
+                    Ok(Some(Event::SeeCards))
+                }
+            }
+            Action::AllowNil | Action::RejectNil => {
+                self.public_state.check_nil_approval(self.player)?;
+                Ok(Some(Event::ApprovesNil(action == Action::AllowNil)))
+            }
+            Action::MakeBid(bid) => {
+                self.public_state.check_bid(self.player, bid)?;
+                Ok(Some(Event::MakeBid(bid)))
+            }
+            Action::PlayCard(card) => {
+                self.public_state.check_card_played(
+                    self.player,
+                    card,
+                    self.hand.ok_or(Error::HandNotSeen)?,
+                )?;
+                Ok(Some(Event::PlayCard(card)))
+            }
+            Action::Concede => {
+                self.public_state.check_concede()?;
+                Ok(Some(Event::Concede))
+            }
+        }
     }
 
     /// Gets the actions that this player may perform at the current time.
     pub fn get_allowed_actions(&self) -> std::collections::HashSet<Action> {
         let mut set = std::collections::HashSet::default();
+        if self.spectator {
+            return set;
+        }
         if !self.can_see_cards(self.player) {
             set.insert(Action::SeeCards);
         }
@@ -194,7 +632,7 @@ impl View {
                                 )
                                 .is_none()
                             })
-                            .map(|bid| Action::MakeBid(bid)),
+                            .map(Action::MakeBid),
                     );
                 }
             }
@@ -217,7 +655,7 @@ impl View {
                                 self.is_trump_broken(),
                             )
                             .iter()
-                            .map(|card| Action::PlayCard(card)),
+                            .map(Action::PlayCard),
                     )
                 }
             }
@@ -230,54 +668,75 @@ impl View {
     }
 
     /// Performs an action.
+    ///
+    /// If the action creates an event then it can be reverted with
+    /// undo_last_local_action() until a response or notification
+    /// is handled.
     pub fn perform_action(
         &mut self,
         action: Action,
-    ) -> Result<Option<Event>, String> {
+    ) -> Result<Option<Event>, Error> {
+        if self.spectator {
+            return Err(Error::SpectatorAction);
+        }
+        // only snapshot the view once the action is known to create an event
+        let event = match self.check_action(action)? {
+            Some(event) => event,
+            None => return Ok(None),
+        };
+        let snapshot = (self.public_state.clone(), self.hand);
         match action {
-            Action::Wait => {
-                let player = match self.get_status() {
-                    Status::WaitingForBid(player) => player,
-                    Status::WaitingForNilConfirmation(player) => player,
-                    Status::WaitingForPlay(player) => player,
-                    Status::GameOver => {
-                        return Err(
-                            "Can not wait when the game is over".to_string()
-                        );
-                    }
-                };
-                if self.player == player {
-                    Err("Can not wait when the game is waiting on you."
-                        .to_string())
-                } else {
-                    Ok(None)
-                }
-            }
-            Action::SeeCards => {
-                if self.get_status() == Status::GameOver {
-                    Err("Can not request to see your cards when the game \
-                    is over"
-                        .to_string())
-                } else if self.public_state.can_see_cards(self.player) {
-                    Err("Can not request to see your cards when you can \
-                    already see them."
-                        .to_string())
-                } else {
-                    Ok(Some(Event::SeeCards))
-                }
-            }
-            Action::AllowNil => self.approve_nil().map(|x| Some(x)),
-            Action::RejectNil => self.reject_nil().map(|x| Some(x)),
-            Action::MakeBid(bid) => self.make_bid(bid).map(|x| Some(x)),
-            Action::PlayCard(card) => self.play_card(card).map(|x| Some(x)),
+            Action::Wait | Action::SeeCards => {}
+            Action::AllowNil => self.approve_nil(true)?,
+            Action::RejectNil => self.approve_nil(false)?,
+            Action::MakeBid(bid) => self.make_bid(bid)?,
+            Action::PlayCard(card) => self.play_card(card)?,
+            Action::Concede => self.public_state.on_concede(self.player)?,
         }
+        self.undo_snapshot = Some(snapshot);
+        Ok(Some(event))
+    }
+
+    /// Gets every allowed action along with the view that results from
+    /// performing it, without modifying this view.
+    ///
+    /// Intended for searching the game tree.
+    /// The resulting views have not handled any response from the server,
+    /// so for example SeeCards does not reveal a hand.
+    /// The order of the successors is unspecified.
+    pub fn successors(&self) -> Vec<(Action, View)> {
+        self.get_allowed_actions()
+            .into_iter()
+            .filter_map(|action| {
+                let mut view = self.clone();
+                view.perform_action(action).ok()?;
+                Some((action, view))
+            })
+            .collect()
+    }
+
+    /// Reverts the last action performed by perform_action() that has not
+    /// been acknowledged by a response or followed by a notification.
+    ///
+    /// Only a single action can be undone.
+    /// The action's event must not have been sent to the server,
+    /// as that would desynchronize this view from the server.
+    pub fn undo_last_local_action(&mut self) -> Result<(), Error> {
+        let (public_state, hand) =
+            self.undo_snapshot.take().ok_or(Error::NothingToUndo)?;
+        self.public_state = public_state;
+        self.hand = hand;
+        Ok(())
     }
 
     /// Handles a response from the server.
-    pub fn handle_response(
-        &mut self,
-        response: Response,
-    ) -> Result<(), String> {
+    ///
+    /// Fails for a spectator, which never performs an action to respond to.
+    pub fn handle_response(&mut self, response: Response) -> Result<(), Error> {
+        if self.spectator {
+            return Err(Error::SpectatorAction);
+        }
+        self.undo_snapshot = None;
         match response {
             Response::Ok => Ok(()),
             Response::Cards(cards) => {
@@ -292,12 +751,29 @@ impl View {
     pub fn handle_notification(
         &mut self,
         notification: Notification,
-    ) -> Result<(), String> {
-        if notification.player == self.player {
-            return Err("Notifications from a player can not be applied to \
-                the player's own view of the game."
-                .to_string());
+    ) -> Result<(), Error> {
+        if notification.player == self.player && !self.spectator {
+            return Err(Error::OwnNotification);
+        }
+        if let Event::MakeBid(_) | Event::ApprovesNil(_) | Event::PlayCard(_) =
+            notification.event
+        {
+            // only the player that the game is waiting on may bid or play
+            match self.get_status() {
+                Status::WaitingForBid(expected)
+                | Status::WaitingForNilConfirmation(expected)
+                | Status::WaitingForPlay(expected)
+                    if expected != notification.player =>
+                {
+                    return Err(Error::NotificationOutOfTurn {
+                        player: notification.player,
+                        expected,
+                    });
+                }
+                _ => {}
+            }
         }
+        self.undo_snapshot = None;
         match notification.event {
             Event::SeeCards => {
                 self.public_state.on_cards_seen(notification.player);
@@ -313,9 +789,31 @@ impl View {
                     .unchecked_on_card_played(notification.player, card)?;
                 self.after_card_played();
             }
+            Event::Concede => {
+                self.public_state.on_concede(notification.player)?;
+            }
         };
         Ok(())
     }
+
+    /// Handles a sequence of notifications from the server in order.
+    ///
+    /// Stops at the first notification that can not be applied, leaving
+    /// every notification before it applied.
+    pub fn handle_notifications(
+        &mut self,
+        notifications: &[Notification],
+    ) -> Result<(), Error> {
+        for (index, notification) in notifications.iter().enumerate() {
+            self.handle_notification(notification.clone()).map_err(
+                |error| Error::NotificationFailed {
+                    index,
+                    error: Box::new(error),
+                },
+            )?;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -686,4 +1184,883 @@ mod test {
         allowed_actions.insert(Action::SeeCards);
         assert_eq!(allowed_actions, view.get_allowed_actions());
     }
+
+    #[test]
+    fn is_lead_safe() {
+        let rules = scoring::ScoringRules::default();
+        let mut view = View::new(Player::One);
+
+        // a large lead near the target can not be lost in one round
+        view.public_state
+            .set_scores([Score::new(45, 0), Score::new(-60, 0)]);
+        assert_eq!(Ok(true), view.is_lead_safe(0, &rules));
+        assert_eq!(Ok(false), view.is_lead_safe(1, &rules));
+
+        // a small lead near the target can be lost in one round
+        view.public_state
+            .set_scores([Score::new(45, 0), Score::new(40, 0)]);
+        assert_eq!(Ok(false), view.is_lead_safe(0, &rules));
+        assert_eq!(Ok(false), view.is_lead_safe(1, &rules));
+
+        // a larger high trick bonus allows a larger swing
+        view.public_state
+            .set_scores([Score::new(45, 0), Score::new(-60, 0)]);
+        let rules = scoring::ScoringRules {
+            high_trick_bonus_tens: 20,
+            ..scoring::ScoringRules::default()
+        };
+        assert_eq!(Ok(false), view.is_lead_safe(0, &rules));
+
+        // there is no third team
+        assert_eq!(
+            Err(Error::InvalidTeamIndex(2)),
+            view.is_lead_safe(2, &rules)
+        );
+    }
+
+    /// Creates a player's view of a game where every player has seen
+    /// their cards and bid three, with the player holding a given hand.
+    fn view_after_bidding(player: Player, hand: card::Set) -> View {
+        let mut view = View::new(player);
+        view.perform_action(Action::SeeCards).unwrap();
+        view.handle_response(Response::Cards(hand)).unwrap();
+        for other_player in player.iter().skip(1) {
+            view.handle_notification(Notification {
+                player: other_player,
+                event: Event::SeeCards,
+            })
+            .unwrap();
+        }
+        for bidder in Player::Two.iter() {
+            if bidder == player {
+                view.perform_action(Action::MakeBid(Bid::Take(3))).unwrap();
+            } else {
+                view.handle_notification(Notification {
+                    player: bidder,
+                    event: Event::MakeBid(Bid::Take(3)),
+                })
+                .unwrap();
+            }
+        }
+        view
+    }
+
+    #[test]
+    fn successors() {
+        let mut view = View::new(Player::Two);
+        view.perform_action(Action::SeeCards).unwrap();
+        view.handle_response(Response::Cards(card::Set::suite(
+            card::Suite::Heart,
+        )))
+        .unwrap();
+
+        let successors = view.successors();
+        let num_bids = view
+            .get_allowed_actions()
+            .iter()
+            .filter(|action| matches!(action, Action::MakeBid(_)))
+            .count();
+        assert_eq!(num_bids, successors.len());
+        for (action, successor) in successors {
+            let bid = match action {
+                Action::MakeBid(Bid::Nil) => continue,
+                Action::MakeBid(bid) => bid,
+                action => panic!("Unexpected action {:?}", action),
+            };
+            assert_eq!(Some(bid), successor.get_bid(Player::Two));
+            assert_eq!(None, view.get_bid(Player::Two));
+        }
+    }
+
+    #[test]
+    fn notification_out_of_turn() {
+        let mut view = view_after_bidding(Player::One, card::Set::default());
+        let play = |player| Notification {
+            player,
+            event: Event::PlayCard(Card::new(
+                card::Suite::Heart,
+                card::Value::Ace,
+            )),
+        };
+
+        // player two leads the first trick
+        assert_eq!(
+            Err(Error::NotificationOutOfTurn {
+                player: Player::Three,
+                expected: Player::Two,
+            }),
+            view.handle_notification(play(Player::Three))
+        );
+        assert_eq!(0, view.get_trick().num_cards_played());
+        view.handle_notification(play(Player::Two)).unwrap();
+    }
+
+    #[test]
+    fn hand_consistency() {
+        let mut view = view_after_bidding(
+            Player::Two,
+            card::Set::suite(card::Suite::Spade),
+        );
+        view.validate_hand_consistency().unwrap();
+
+        view.perform_action(Action::PlayCard(Card::new(
+            card::Suite::Spade,
+            card::Value::Ace,
+        )))
+        .unwrap();
+        view.validate_hand_consistency().unwrap();
+
+        // a card reappearing in the hand is detected
+        view.hand
+            .as_mut()
+            .unwrap()
+            .insert(Card::new(card::Suite::Spade, card::Value::Ace));
+        assert!(view.validate_hand_consistency().is_err());
+    }
+
+    #[test]
+    fn can_lead_spades() {
+        // trump is not broken and there are other suites to lead
+        let mixed_hand = card::Set::suite(card::Suite::Spade)
+            | card::Set::suite(card::Suite::Heart);
+        let view = view_after_bidding(Player::Two, mixed_hand);
+        assert!(!view.can_lead_spades());
+
+        // only spades can be lead
+        let view = view_after_bidding(
+            Player::Two,
+            card::Set::suite(card::Suite::Spade),
+        );
+        assert!(view.can_lead_spades());
+
+        // not leading
+        let view = View::new(Player::Two);
+        assert!(!view.can_lead_spades());
+    }
+
+    #[test]
+    fn handle_notifications() {
+        let see_cards: Vec<Notification> = Player::Two
+            .iter()
+            .skip(1)
+            .map(|player| Notification {
+                player,
+                event: Event::SeeCards,
+            })
+            .collect();
+
+        // valid batch
+        let mut view = View::new(Player::Two);
+        view.handle_notifications(&see_cards).unwrap();
+        for player in Player::Two.iter().skip(1) {
+            assert!(view.can_see_cards(player));
+        }
+
+        // player four can not bid before player two
+        let mut notifications = see_cards.clone();
+        notifications.insert(
+            1,
+            Notification {
+                player: Player::Four,
+                event: Event::MakeBid(Bid::Take(3)),
+            },
+        );
+        let mut view = View::new(Player::Two);
+        let error = view.handle_notifications(&notifications).unwrap_err();
+        assert_eq!(
+            Error::NotificationFailed {
+                index: 1,
+                error: Box::new(Error::NotificationOutOfTurn {
+                    player: Player::Four,
+                    expected: Player::Two,
+                })
+            },
+            error
+        );
+        // stopped at the invalid notification
+        assert!(view.can_see_cards(Player::Three));
+        assert!(!view.can_see_cards(Player::Four));
+    }
+
+    #[test]
+    fn hand_sizes() {
+        let mut view = view_after_bidding(
+            Player::Two,
+            card::Set::suite(card::Suite::Spade),
+        );
+        assert_eq!(player::Array::from_value(&13), view.hand_sizes());
+
+        // play a trick
+        view.perform_action(Action::PlayCard(Card::new(
+            card::Suite::Spade,
+            card::Value::Ace,
+        )))
+        .unwrap();
+        for player in view.player.iter().skip(1) {
+            view.handle_notification(Notification {
+                player,
+                event: Event::PlayCard(Card::new(
+                    card::Suite::Heart,
+                    card::Value::from_index(player.to_index()).unwrap(),
+                )),
+            })
+            .unwrap();
+        }
+
+        assert_eq!(player::Array::from_value(&12), view.hand_sizes());
+        assert_eq!(
+            view.get_hand().unwrap().len(),
+            view.hand_sizes()[view.player] as usize
+        );
+    }
+
+    #[test]
+    fn forced_play() {
+        let heart = Card::new(card::Suite::Heart, card::Value::Number(9));
+        let mut hand = card::Set::suite(card::Suite::Spade);
+        hand.remove(Card::new(card::Suite::Spade, card::Value::Ace));
+        hand.insert(heart);
+        let mut view = view_after_bidding(Player::Three, hand);
+
+        // not our turn
+        assert_eq!(None, view.forced_play());
+
+        // hearts are lead and we only have one
+        view.handle_notification(Notification {
+            player: Player::Two,
+            event: Event::PlayCard(Card::new(
+                card::Suite::Heart,
+                card::Value::King,
+            )),
+        })
+        .unwrap();
+        assert_eq!(Some(heart), view.forced_play());
+
+        // leading with only one card that is not trump
+        let view = view_after_bidding(Player::Two, hand);
+        assert_eq!(Some(heart), view.forced_play());
+
+        // leading with many choices
+        hand.insert(Card::new(card::Suite::Club, card::Value::Number(2)));
+        let view = view_after_bidding(Player::Two, hand);
+        assert_eq!(None, view.forced_play());
+    }
+
+    #[test]
+    fn phase() {
+        let view = View::new(Player::Three);
+        assert_eq!(Phase::Bidding, view.phase());
+
+        let view = view_after_bidding(
+            Player::Three,
+            card::Set::suite(card::Suite::Club),
+        );
+        assert_eq!(Phase::Playing, view.phase());
+    }
+
+    #[test]
+    fn is_eliminated() {
+        let mut view = View::new(Player::Two);
+        view.public_state
+            .set_scores([Score::new(-100, 0), Score::default()]);
+
+        // not eliminated before bidding is complete
+        assert_eq!(Ok(false), view.is_eliminated(0));
+
+        let mut view = view_after_bidding(
+            Player::Two,
+            card::Set::suite(card::Suite::Club),
+        );
+        view.public_state
+            .set_scores([Score::new(-100, 0), Score::default()]);
+        assert_eq!(Ok(true), view.is_eliminated(0));
+        assert_eq!(Ok(false), view.is_eliminated(1));
+
+        view.public_state
+            .set_scores([Score::default(), Score::default()]);
+        assert_eq!(Ok(false), view.is_eliminated(0));
+        assert_eq!(Ok(false), view.is_eliminated(1));
+
+        // there is no third team
+        assert_eq!(Err(Error::InvalidTeamIndex(2)), view.is_eliminated(2));
+    }
+
+    #[test]
+    fn winning_plays() {
+        let high_heart = Card::new(card::Suite::Heart, card::Value::King);
+        let low_heart = Card::new(card::Suite::Heart, card::Value::Number(2));
+        let hand = card::Set::suite(card::Suite::Club)
+            | [high_heart, low_heart].iter().collect();
+        let mut view = view_after_bidding(Player::Three, hand);
+
+        // not our turn
+        assert!(view.winning_plays().is_empty());
+
+        view.handle_notification(Notification {
+            player: Player::Two,
+            event: Event::PlayCard(Card::new(
+                card::Suite::Heart,
+                card::Value::Number(9),
+            )),
+        })
+        .unwrap();
+        assert_eq!(
+            [high_heart].iter().collect::<card::Set>(),
+            view.winning_plays()
+        );
+    }
+
+    #[test]
+    fn players_void_in() {
+        let mut view = view_after_bidding(
+            Player::One,
+            card::Set::suite(card::Suite::Club),
+        );
+        assert!(view.players_void_in(card::Suite::Heart).is_empty());
+
+        // player three does not follow the lead of hearts
+        let plays = [
+            (
+                Player::Two,
+                Card::new(card::Suite::Heart, card::Value::King),
+            ),
+            (
+                Player::Three,
+                Card::new(card::Suite::Diamond, card::Value::Ace),
+            ),
+        ];
+        for (player, card) in plays.iter() {
+            view.handle_notification(Notification {
+                player: *player,
+                event: Event::PlayCard(*card),
+            })
+            .unwrap();
+        }
+
+        assert_eq!(
+            vec![Player::Three],
+            view.players_void_in(card::Suite::Heart)
+        );
+        assert!(view.players_void_in(card::Suite::Diamond).is_empty());
+    }
+
+    #[test]
+    fn is_known_void() {
+        let mut view = view_after_bidding(
+            Player::One,
+            card::Set::suite(card::Suite::Club),
+        );
+
+        // player three discards a heart on a led diamond
+        let plays = [
+            (
+                Player::Two,
+                Card::new(card::Suite::Diamond, card::Value::King),
+            ),
+            (
+                Player::Three,
+                Card::new(card::Suite::Heart, card::Value::Number(2)),
+            ),
+        ];
+        for (player, card) in plays.iter() {
+            view.handle_notification(Notification {
+                player: *player,
+                event: Event::PlayCard(*card),
+            })
+            .unwrap();
+        }
+
+        assert!(view.is_known_void(Player::Three, card::Suite::Diamond));
+        assert!(!view.is_known_void(Player::Three, card::Suite::Heart));
+        assert!(!view.is_known_void(Player::Two, card::Suite::Diamond));
+
+        // the void is reset at the start of the next round
+        view.public_state.restart_round();
+        assert!(!view.is_known_void(Player::Three, card::Suite::Diamond));
+    }
+
+    #[test]
+    fn possible_cards() {
+        let clubs = card::Set::suite(card::Suite::Club);
+        let mut view = view_after_bidding(Player::One, clubs);
+        assert_eq!(clubs, view.possible_cards(Player::One));
+        assert_eq!(!clubs, view.possible_cards(Player::Three));
+
+        // player three discards a heart on a led diamond
+        let king = Card::new(card::Suite::Diamond, card::Value::King);
+        let two = Card::new(card::Suite::Heart, card::Value::Number(2));
+        for (player, card) in [(Player::Two, king), (Player::Three, two)].iter()
+        {
+            view.handle_notification(Notification {
+                player: *player,
+                event: Event::PlayCard(*card),
+            })
+            .unwrap();
+        }
+
+        let possible = view.possible_cards(Player::Three);
+        assert!(possible.of_suite(card::Suite::Diamond).is_empty());
+        assert!(possible.of_suite(card::Suite::Club).is_empty());
+        assert!(!possible.contains(two));
+        assert_eq!(12, possible.of_suite(card::Suite::Heart).len());
+        assert_eq!(13, possible.of_suite(card::Suite::Spade).len());
+
+        let possible = view.possible_cards(Player::Four);
+        assert_eq!(12, possible.of_suite(card::Suite::Diamond).len());
+        assert!(!possible.contains(king));
+    }
+
+    #[test]
+    fn my_played_cards() {
+        let mut view = view_after_bidding(
+            Player::Two,
+            card::Set::suite(card::Suite::Spade),
+        );
+        assert!(view.my_played_cards().is_empty());
+
+        let ace = Card::new(card::Suite::Spade, card::Value::Ace);
+        let king = Card::new(card::Suite::Spade, card::Value::King);
+        view.perform_action(Action::PlayCard(ace)).unwrap();
+        for (i, player) in Player::Three.iter().take(3).enumerate() {
+            view.handle_notification(Notification {
+                player,
+                event: Event::PlayCard(Card::from_index(13 + i as u8).unwrap()),
+            })
+            .unwrap();
+        }
+        view.perform_action(Action::PlayCard(king)).unwrap();
+
+        let expected: card::Set = [ace, king].iter().collect();
+        assert_eq!(expected, view.my_played_cards());
+    }
+
+    #[test]
+    fn final_standings() {
+        let mut view = View::new(Player::Three);
+        assert_eq!(None, view.final_standings());
+
+        let scores = [Score::new(48, 3), Score::new(51, 2)];
+        view.public_state.set_scores(scores);
+        assert_eq!(Status::GameOver, view.get_status());
+        assert_eq!(Some((scores, 1)), view.final_standings());
+    }
+
+    #[test]
+    fn can_follow_suit() {
+        let hand = card::Set::suite(card::Suite::Club)
+            .iter()
+            .take(12)
+            .chain(Some(Card::new(card::Suite::Heart, card::Value::Ace)))
+            .collect();
+        let mut view = view_after_bidding(Player::Three, hand);
+        // not this player's turn
+        assert_eq!(None, view.can_follow_suit());
+
+        view.handle_notification(Notification {
+            player: Player::Two,
+            event: Event::PlayCard(Card::new(
+                card::Suite::Heart,
+                card::Value::Number(4),
+            )),
+        })
+        .unwrap();
+        assert_eq!(Some(true), view.can_follow_suit());
+
+        let mut view = view_after_bidding(Player::Three, hand);
+        view.handle_notification(Notification {
+            player: Player::Two,
+            event: Event::PlayCard(Card::new(
+                card::Suite::Diamond,
+                card::Value::Number(4),
+            )),
+        })
+        .unwrap();
+        assert_eq!(Some(false), view.can_follow_suit());
+    }
+
+    #[test]
+    fn concede() {
+        let mut view = View::new(Player::One);
+        assert_eq!(
+            Ok(Some(Event::Concede)),
+            view.perform_action(Action::Concede)
+        );
+        assert_eq!(Status::GameOver, view.get_status());
+        assert_eq!(
+            Some(([Score::default(), Score::default()], 1)),
+            view.final_standings()
+        );
+        assert!(view.perform_action(Action::Concede).is_err());
+
+        let mut view = View::new(Player::One);
+        view.handle_notification(Notification {
+            player: Player::Two,
+            event: Event::Concede,
+        })
+        .unwrap();
+        assert_eq!(Some(0), view.final_standings().map(|(_, team)| team));
+    }
+
+    #[test]
+    fn team_can_still_win_trick() {
+        let card = |s: &str| s.parse::<Card>().unwrap();
+        let play_trick = |plays: [&str; 3]| {
+            let mut view = view_after_bidding(
+                Player::Two,
+                card::Set::suite(card::Suite::Club),
+            );
+            view.perform_action(Action::PlayCard(card("C2"))).unwrap();
+            for (player, play) in Player::Three.iter().zip(plays.iter()) {
+                if play.is_empty() {
+                    break;
+                }
+                view.handle_notification(Notification {
+                    player,
+                    event: Event::PlayCard(card(play)),
+                })
+                .unwrap();
+            }
+            view
+        };
+
+        assert_eq!(None, View::new(Player::Two).team_can_still_win_trick());
+
+        // the teammate holds the winning card
+        let view = play_trick(["C3", "CK", ""]);
+        assert_eq!(Some(true), view.team_can_still_win_trick());
+
+        // the teammate has not played and could trump
+        let view = play_trick(["CA", "", ""]);
+        assert_eq!(Some(true), view.team_can_still_win_trick());
+
+        // the opponent is winning and the team has played
+        let view = play_trick(["SA", "C5", ""]);
+        assert_eq!(Some(false), view.team_can_still_win_trick());
+
+        // the teammate is known to only hold diamonds
+        let mut view = view_after_bidding(
+            Player::Two,
+            card::Set::suite(card::Suite::Club),
+        );
+        // player four does not follow clubs, hearts, or spades
+        let plays = [
+            (Player::Two, "C2"),
+            (Player::Three, "D3"),
+            (Player::Four, "D4"),
+            (Player::One, "S2"),
+            (Player::One, "H2"),
+            (Player::Two, "C3"),
+            (Player::Three, "H3"),
+            (Player::Four, "D5"),
+            (Player::Three, "S3"),
+            (Player::Four, "D6"),
+            (Player::One, "S4"),
+            (Player::Two, "C4"),
+            (Player::One, "HK"),
+        ];
+        for (player, play) in plays.iter() {
+            if *player == Player::Two {
+                view.perform_action(Action::PlayCard(card(play))).unwrap();
+            } else {
+                view.handle_notification(Notification {
+                    player: *player,
+                    event: Event::PlayCard(card(play)),
+                })
+                .unwrap();
+            }
+        }
+        assert!(view.is_known_void(Player::Four, card::Suite::Spade));
+        assert_eq!(Some(false), view.team_can_still_win_trick());
+    }
+
+    /// Plays a round where every player bids three and the first player to
+    /// play takes every trick.
+    fn play_round(view: &mut View) {
+        let first_player = match view.get_status() {
+            Status::WaitingForBid(player) => player,
+            status => panic!("Unexpected status {:?}", status),
+        };
+        for player in first_player.iter() {
+            view.public_state.on_cards_seen(player);
+            view.public_state.on_bid(player, Bid::Take(3)).unwrap();
+        }
+        for _ in 0..13 {
+            for (i, player) in first_player.iter().enumerate() {
+                let value = if i == 0 {
+                    card::Value::Ace
+                } else {
+                    card::Value::Number(2 + i as u8)
+                };
+                view.public_state
+                    .unchecked_on_card_played(
+                        player,
+                        Card::new(card::Suite::Diamond, value),
+                    )
+                    .unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn round_number() {
+        let mut view = View::new(Player::Four);
+        assert_eq!(0, view.get_round_number());
+        play_round(&mut view);
+        assert_eq!(1, view.get_round_number());
+
+        // restarting a round does not complete it
+        view.public_state.restart_round();
+        assert_eq!(1, view.get_round_number());
+        play_round(&mut view);
+        assert_eq!(2, view.get_round_number());
+    }
+
+    #[test]
+    fn first_bidder() {
+        let view = View::new(Player::One);
+        assert_eq!(Player::One, view.get_dealer());
+        assert_eq!(Player::Two, view.get_first_bidder());
+        assert_eq!(Status::WaitingForBid(Player::Two), view.get_status());
+        assert_eq!(Trick::new(Player::Two), view.get_trick());
+    }
+
+    #[test]
+    fn dealer_rotates() {
+        let mut view = View::new(Player::Three);
+        assert_eq!(Player::One, view.get_dealer());
+        play_round(&mut view);
+        assert_eq!(Player::Two, view.get_dealer());
+        assert_eq!(Player::Three, view.get_first_bidder());
+        play_round(&mut view);
+        assert_eq!(Player::Three, view.get_dealer());
+    }
+
+    #[test]
+    fn round_history() {
+        let mut view = View::new(Player::One);
+        assert_eq!(0, view.round_history().count());
+
+        play_round(&mut view);
+        play_round(&mut view);
+
+        let history: Vec<_> = view.round_history().collect();
+        assert_eq!(2, history.len());
+
+        let (index, results, scores) = history[0];
+        assert_eq!(0, index);
+        assert_eq!(view.get_round_results()[0], results);
+        assert_eq!([Score::new(-6, 0), Score::new(6, 7)], scores);
+
+        let (index, results, scores) = history[1];
+        assert_eq!(1, index);
+        assert_eq!(view.get_round_results()[1], results);
+        assert_eq!([Score::new(0, 7), Score::new(0, 7)], scores);
+        assert_eq!(view.get_scores(), scores);
+    }
+
+    #[test]
+    fn num_legal_plays() {
+        let hand: card::Set = card::Set::suite(card::Suite::Club)
+            .iter()
+            .take(12)
+            .chain(Some(Card::new(card::Suite::Heart, card::Value::Ace)))
+            .collect();
+        let after_lead = |lead: Card| {
+            let mut view = view_after_bidding(Player::Three, hand);
+            assert_eq!(0, view.num_legal_plays());
+            view.handle_notification(Notification {
+                player: Player::Two,
+                event: Event::PlayCard(lead),
+            })
+            .unwrap();
+            view
+        };
+
+        // must follow with the only heart
+        let view =
+            after_lead(Card::new(card::Suite::Heart, card::Value::Number(4)));
+        assert_eq!(1, view.num_legal_plays());
+
+        // void in diamonds so anything can be played
+        let view =
+            after_lead(Card::new(card::Suite::Diamond, card::Value::Number(4)));
+        assert_eq!(13, view.num_legal_plays());
+    }
+
+    #[test]
+    fn undo_last_local_action() {
+        let mut view = View::new(Player::Two);
+        assert!(view.undo_last_local_action().is_err());
+
+        // see cards, which is acknowledged by the server
+        view.perform_action(Action::SeeCards).unwrap();
+        view.handle_response(Response::Cards(card::Set::suite(
+            card::Suite::Heart,
+        )))
+        .unwrap();
+        assert!(view.undo_last_local_action().is_err());
+
+        let before_bid = view.get_allowed_actions();
+        view.perform_action(Action::MakeBid(Bid::Take(4))).unwrap();
+        assert_ne!(before_bid, view.get_allowed_actions());
+
+        view.undo_last_local_action().unwrap();
+        assert_eq!(before_bid, view.get_allowed_actions());
+        assert_eq!(None, view.get_bid(Player::Two));
+        assert!(view.get_hand().is_some());
+
+        // only a single action can be undone
+        assert!(view.undo_last_local_action().is_err());
+    }
+
+    #[test]
+    fn undo_ignores_actions_without_events() {
+        let mut view = View::new(Player::Three);
+
+        // waiting and failing actions do not create an event to undo
+        assert_eq!(Ok(None), view.perform_action(Action::Wait));
+        assert_eq!(
+            Err(Error::NoNilPending),
+            view.perform_action(Action::AllowNil)
+        );
+        assert_eq!(
+            Err(Error::NotYourTurnToBid),
+            view.perform_action(Action::MakeBid(Bid::Take(3)))
+        );
+        assert_eq!(Err(Error::NothingToUndo), view.undo_last_local_action());
+
+        // a failed action does not replace the snapshot of an earlier one
+        view.perform_action(Action::Concede).unwrap();
+        assert!(view.perform_action(Action::Concede).is_err());
+        view.undo_last_local_action().unwrap();
+        assert_eq!(Status::WaitingForBid(Player::Two), view.get_status());
+    }
+
+    #[test]
+    fn spectator() {
+        let mut view = View::spectator();
+        assert!(view.is_spectator());
+        assert!(!View::new(Player::One).is_spectator());
+        assert!(view.get_allowed_actions().is_empty());
+        assert!(view.perform_action(Action::SeeCards).is_err());
+
+        for player in Player::Two.iter() {
+            for event in [Event::SeeCards, Event::MakeBid(Bid::Take(3))].iter()
+            {
+                view.handle_notification(Notification {
+                    player,
+                    event: *event,
+                })
+                .unwrap();
+                assert!(view.get_allowed_actions().is_empty());
+            }
+            assert_eq!(Some(Bid::Take(3)), view.get_bid(player));
+        }
+
+        assert!(view.bidding_complete());
+        assert_eq!(Status::WaitingForPlay(Player::Two), view.get_status());
+        assert_eq!(None, view.get_hand());
+        assert!(view.perform_action(Action::Wait).is_err());
+    }
+
+    #[test]
+    fn spectator_response() {
+        let mut view = View::spectator();
+        assert_eq!(
+            Err(Error::SpectatorAction),
+            view.handle_response(Response::Cards(card::Set::suite(
+                card::Suite::Spade
+            )))
+        );
+        assert_eq!(None, view.get_hand());
+        assert!(!view.can_see_cards(Player::One));
+        assert_eq!(
+            Err(Error::SpectatorAction),
+            view.handle_response(Response::Ok)
+        );
+    }
+
+    #[test]
+    fn spectator_player_relative_getters() {
+        let mut view = View::spectator();
+        for player in Player::Two.iter() {
+            for event in [Event::SeeCards, Event::MakeBid(Bid::Take(3))].iter()
+            {
+                view.handle_notification(Notification {
+                    player,
+                    event: *event,
+                })
+                .unwrap();
+            }
+        }
+
+        // player one does not follow the heart led by player two
+        let plays = [
+            (Player::Two, Card::new(card::Suite::Heart, card::Value::Ace)),
+            (
+                Player::Three,
+                Card::new(card::Suite::Heart, card::Value::King),
+            ),
+            (
+                Player::Four,
+                Card::new(card::Suite::Heart, card::Value::Queen),
+            ),
+            (
+                Player::One,
+                Card::new(card::Suite::Club, card::Value::Number(2)),
+            ),
+        ];
+        for (player, card) in plays.iter() {
+            view.handle_notification(Notification {
+                player: *player,
+                event: Event::PlayCard(*card),
+            })
+            .unwrap();
+        }
+
+        assert_eq!(vec![Player::One], view.players_void_in(card::Suite::Heart));
+        assert!(view.my_played_cards().is_empty());
+        assert_eq!(None, view.team_can_still_win_trick());
+        assert!(!view.can_lead_spades());
+    }
+
+    #[test]
+    fn get_winning_team() {
+        let mut view = View::new(Player::Four);
+        assert_eq!(None, view.get_winning_team());
+
+        view.public_state
+            .set_scores([Score::new(52, 0), Score::new(47, 8)]);
+        assert_eq!(Status::GameOver, view.get_status());
+        assert_eq!(Some(0), view.get_winning_team());
+
+        view.public_state
+            .set_scores([Score::new(-10, 0), Score::new(40, 0)]);
+        assert_eq!(Some(1), view.get_winning_team());
+    }
+
+    #[test]
+    fn get_unseen_cards() {
+        let hand = card::Set::suite(card::Suite::Spade);
+        let mut view = view_after_bidding(Player::Two, hand);
+        assert_eq!(!hand, view.get_unseen_cards());
+
+        let ace = Card::new(card::Suite::Spade, card::Value::Ace);
+        view.perform_action(Action::PlayCard(ace)).unwrap();
+        assert_eq!(39, view.get_unseen_cards().len());
+        assert!(!view.get_unseen_cards().contains(ace));
+
+        let mut played = card::Set::default();
+        for (i, player) in Player::Three.iter().take(3).enumerate() {
+            let card = Card::from_index(13 + i as u8).unwrap();
+            played.insert(card);
+            view.handle_notification(Notification {
+                player,
+                event: Event::PlayCard(card),
+            })
+            .unwrap();
+            assert_eq!(38 - i, view.get_unseen_cards().len());
+        }
+        assert_eq!(!hand - played, view.get_unseen_cards());
+    }
 }
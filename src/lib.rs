@@ -5,12 +5,18 @@
 //! The main difference from other variants is a minimum team bid of four tricks,
 //! bidding 10 tricks is worth 200 points, and not making the number of tricks
 //! bid causes nils to fail even if the player bidding nil succeeds.
+//!
+//! Enable the `serde` feature to implement `serde::Serialize` and
+//! `serde::Deserialize` for the data model.
 
 #![warn(missing_docs)]
 
 pub mod card;
 pub use card::Card;
 
+mod error;
+pub use error::Error;
+
 pub mod game;
 
 pub mod player;
@@ -21,3 +27,6 @@ pub use scoring::{Bid, Score, TeamRoundResult};
 
 pub mod trick;
 pub use trick::Trick;
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_test;
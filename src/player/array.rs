@@ -2,6 +2,7 @@ use super::Player;
 
 /// Length four array that uses `Player` as an index.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Array<T>
 where
     T: Clone,
@@ -26,6 +27,24 @@ where
         Self { array }
     }
 
+    /// Creates a new array by calling a function with each player,
+    /// starting at `Player::One`.
+    pub fn from_fn<F: FnMut(Player) -> T>(mut f: F) -> Self {
+        Self {
+            array: [
+                f(Player::One),
+                f(Player::Two),
+                f(Player::Three),
+                f(Player::Four),
+            ],
+        }
+    }
+
+    /// Creates a new array by applying a function to every value.
+    pub fn map<U: Clone, F: FnMut(&T) -> U>(&self, mut f: F) -> Array<U> {
+        Array::from_fn(|player| f(&self[player]))
+    }
+
     /// Fills in an array with a value.
     pub fn fill(&mut self, value: &T) {
         for entry in self.array.iter_mut() {
@@ -37,6 +56,45 @@ where
     pub fn iter<'a>(&'a self) -> core::slice::Iter<'a, T> {
         self.array.iter()
     }
+
+    /// Returns a mutable iterator over the values in an array.
+    pub fn iter_mut<'a>(&'a mut self) -> core::slice::IterMut<'a, T> {
+        self.array.iter_mut()
+    }
+
+    /// Returns an iterator over the values in an array
+    /// along with the player that each belongs to.
+    ///
+    /// Iterates in order starting at `Player::One`.
+    pub fn iter_with_players(&self) -> impl Iterator<Item = (Player, &T)> {
+        Player::One.iter().zip(self.array.iter())
+    }
+}
+
+/// Convenience functions for arrays where not every player has a value.
+impl<T> Array<Option<T>>
+where
+    T: Clone,
+{
+    /// Gets a player's value, if they have one.
+    pub fn get(&self, player: Player) -> Option<&T> {
+        self[player].as_ref()
+    }
+
+    /// Sets a player's value.
+    pub fn set(&mut self, player: Player, value: T) {
+        self[player] = Some(value);
+    }
+
+    /// Returns an iterator over the players that have a value
+    /// along with their value.
+    ///
+    /// Iterates in order starting at `Player::One`.
+    pub fn iter_present(&self) -> impl Iterator<Item = (Player, &T)> {
+        Player::One
+            .iter()
+            .filter_map(move |player| self.get(player).map(|x| (player, x)))
+    }
 }
 
 /// lookup operator
@@ -101,3 +159,69 @@ where
 
 /// Conditionally Eq
 impl<T> Eq for Array<T> where T: Eq + Clone {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Bid;
+
+    #[test]
+    fn option_get_and_set() {
+        let mut bids = Array::<Option<Bid>>::default();
+        assert_eq!(None, bids.get(Player::Two));
+
+        bids.set(Player::Two, Bid::Nil);
+        assert_eq!(Some(&Bid::Nil), bids.get(Player::Two));
+        assert_eq!(None, bids.get(Player::Three));
+    }
+
+    #[test]
+    fn iter_present() {
+        let mut bids = Array::<Option<Bid>>::default();
+        bids.set(Player::Four, Bid::Take(4));
+        bids.set(Player::Two, Bid::Take(2));
+
+        let present: Vec<(Player, &Bid)> = bids.iter_present().collect();
+        assert_eq!(
+            vec![(Player::Two, &Bid::Take(2)), (Player::Four, &Bid::Take(4))],
+            present
+        );
+    }
+
+    #[test]
+    fn from_fn_and_map() {
+        let indices = Array::from_fn(Player::to_index);
+        assert_eq!(Array::from_array([0, 1, 2, 3]), indices);
+
+        let mut bids = Array::<Option<Bid>>::default();
+        bids.set(Player::Three, Bid::Nil);
+        assert_eq!(
+            Array::from_array([false, false, true, false]),
+            bids.map(Option::is_some)
+        );
+    }
+
+    #[test]
+    fn iter_with_players() {
+        let names = Array::from_array(["one", "two", "three", "four"]);
+        let pairs: Vec<(Player, &&str)> = names.iter_with_players().collect();
+        assert_eq!(
+            vec![
+                (Player::One, &"one"),
+                (Player::Two, &"two"),
+                (Player::Three, &"three"),
+                (Player::Four, &"four")
+            ],
+            pairs
+        );
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut values = Array::from_array([1u8, 2, 3, 4]);
+        for value in values.iter_mut() {
+            *value *= 2;
+        }
+        assert_eq!(Array::from_array([2, 4, 6, 8]), values);
+    }
+}
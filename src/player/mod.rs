@@ -6,8 +6,11 @@ pub use iterator::Iterator;
 mod array;
 pub use array::Array;
 
+use crate::Error;
+
 /// The possible players.
-#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Player {
     /// Starts the game as the dealer.
     One,
@@ -21,13 +24,13 @@ pub enum Player {
 
 impl Player {
     /// Creates a Player from an index in the range of [0, 4).
-    pub fn from_index(index: u8) -> Result<Self, String> {
+    pub fn from_index(index: u8) -> Result<Self, Error> {
         match index {
             0 => Ok(Player::One),
             1 => Ok(Player::Two),
             2 => Ok(Player::Three),
             3 => Ok(Player::Four),
-            _ => Err(format!("Invalid player index: {}", index)),
+            _ => Err(Error::InvalidPlayerIndex(index)),
         }
     }
 
@@ -66,6 +69,14 @@ impl Player {
         self.next().next()
     }
 
+    /// Gets the index of a player's team.
+    ///
+    /// Players One and Three are on team 0 while players Two and Four
+    /// are on team 1.
+    pub fn team_index(self) -> u8 {
+        self.to_index() % 2
+    }
+
     /// Gets an iterator that will iterate over all players
     /// starting at this player without repetition.
     pub fn iter(self) -> Iterator {
@@ -73,7 +84,23 @@ impl Player {
     }
 }
 
+/// Gets the two players on a team given the team's index.
+pub fn team_members(team_index: u8) -> Result<[Player; 2], Error> {
+    match team_index {
+        0 => Ok([Player::One, Player::Three]),
+        1 => Ok([Player::Two, Player::Four]),
+        _ => Err(Error::InvalidTeamIndex(team_index)),
+    }
+}
+
+/// Displays as "Player 1" through "Player 4".
 impl std::fmt::Display for Player {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Player {}", self.to_index() + 1)
+    }
+}
+
+impl std::fmt::Debug for Player {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Player::One => write!(f, "Player::One"),
@@ -127,4 +154,28 @@ mod test {
         assert_eq!(Player::Three, Player::Three.iter().next().unwrap());
         assert_eq!(Player::Four, Player::Four.iter().next().unwrap());
     }
+
+    #[test]
+    fn team_index() {
+        assert_eq!(0, Player::One.team_index());
+        assert_eq!(1, Player::Two.team_index());
+        assert_eq!(0, Player::Three.team_index());
+        assert_eq!(1, Player::Four.team_index());
+
+        for team_index in 0..2 {
+            let members = team_members(team_index).unwrap();
+            assert_eq!(members[0].teammate(), members[1]);
+            for player in members.iter() {
+                assert_eq!(team_index, player.team_index());
+            }
+        }
+        assert!(team_members(2).is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("Player 1", format!("{}", Player::One));
+        assert_eq!("Player 3", format!("{}", Player::Three));
+        assert_eq!("Player::Three", format!("{:?}", Player::Three));
+    }
 }
@@ -1,5 +1,8 @@
+use crate::Error;
+
 /// A player's bid.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Bid {
     /// A player must take no tricks.  They decided before they saw their cards.
     BlindNil,
@@ -19,14 +22,12 @@ impl Bid {
     pub(crate) fn get_compatibility_error(
         self,
         teammate_bid: Option<Bid>,
-    ) -> Option<&'static str> {
+    ) -> Option<Error> {
         if let Some(teammate_bid_) = teammate_bid {
             if super::bid_util::is_any_nil(teammate_bid_)
                 && super::bid_util::is_any_nil(self)
             {
-                return Some(
-                    "Both players in team can not bid nil or blind nil.",
-                );
+                return Some(Error::IllegalBid);
             }
         }
         if let Bid::Take(tricks_claimed) = self {
@@ -37,13 +38,47 @@ impl Bid {
                     0
                 };
             if team_tricks_claimed > 13 {
-                return Some("Can not bid more than 13 tricks as a team.");
+                return Some(Error::TeamBidTooHigh);
             }
         }
         None
     }
 }
 
+impl std::fmt::Display for Bid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Bid::BlindNil => write!(f, "Blind Nil"),
+            Bid::Nil => write!(f, "Nil"),
+            Bid::Take(tricks) => write!(f, "Take {}", tricks),
+        }
+    }
+}
+
+impl std::str::FromStr for Bid {
+    type Err = Error;
+
+    /// Parses a bid as written by Display, ignoring case and whitespace.
+    ///
+    /// A bare number such as "5" is also parsed as a Take bid.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words: Vec<String> =
+            s.split_whitespace().map(str::to_lowercase).collect();
+        let words: Vec<&str> = words.iter().map(String::as_str).collect();
+        let tricks = match words.as_slice() {
+            ["blind", "nil"] => return Ok(Bid::BlindNil),
+            ["nil"] => return Ok(Bid::Nil),
+            ["take", tricks] | [tricks] => tricks,
+            _ => return Err(Error::InvalidBidString(s.trim().to_string())),
+        };
+        match tricks.parse::<u8>() {
+            Ok(tricks) if tricks <= 13 => Ok(Bid::Take(tricks)),
+            Ok(tricks) => Err(Error::BidTooHigh(tricks)),
+            Err(_) => Err(Error::InvalidBidString(s.trim().to_string())),
+        }
+    }
+}
+
 impl Default for Generator {
     fn default() -> Self {
         Self {
@@ -102,11 +137,7 @@ mod test {
     fn generator_does_not_yield_out_of_bounds() {
         assert!(Generator::default()
             .find(|x| if let Bid::Take(amount) = *x {
-                if amount > 13 {
-                    true
-                } else {
-                    false
-                }
+                amount > 13
             } else {
                 false
             })
@@ -169,4 +200,38 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn display() {
+        assert_eq!("Blind Nil", Bid::BlindNil.to_string());
+        assert_eq!("Nil", Bid::Nil.to_string());
+        assert_eq!("Take 5", Bid::Take(5).to_string());
+    }
+
+    #[test]
+    fn round_trip_string() {
+        for bid in Generator::default() {
+            assert_eq!(Ok(bid), bid.to_string().parse());
+            assert_eq!(Ok(bid), bid.to_string().to_uppercase().parse());
+        }
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(Ok(Bid::Take(5)), "5".parse());
+        assert_eq!(Ok(Bid::Take(13)), " take  13 ".parse());
+        assert_eq!(Ok(Bid::BlindNil), "blind nil".parse());
+        assert_eq!(Ok(Bid::Nil), "nIL".parse());
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        assert_eq!(Err(Error::BidTooHigh(14)), "Take 14".parse::<Bid>());
+        assert!("14".parse::<Bid>().is_err());
+        assert!("".parse::<Bid>().is_err());
+        assert!("take".parse::<Bid>().is_err());
+        assert!("take -1".parse::<Bid>().is_err());
+        assert!("blind".parse::<Bid>().is_err());
+        assert!("nil nil".parse::<Bid>().is_err());
+    }
 }
@@ -1,4 +1,5 @@
 /// Internal module for querying information from bids related to scoring.
+use super::ScoringRules;
 use crate::Bid;
 
 /// Gets if this bid is a any kind of nil bid (nil or blind nil) or not.
@@ -29,15 +30,16 @@ pub fn num_tricks(bid: Bid) -> u8 {
 }
 
 /// Gets the number of tricks that a team will take, taking into
-/// account the minimum bid of four.
-pub fn num_team_tricks(bid1: Bid, bid2: Bid) -> u8 {
-    std::cmp::max(4, num_tricks(bid1) + num_tricks(bid2))
+/// account the minimum team bid of the rules.
+pub fn num_team_tricks(bid1: Bid, bid2: Bid, rules: &ScoringRules) -> u8 {
+    std::cmp::max(rules.min_team_bid, num_tricks(bid1) + num_tricks(bid2))
 }
 
-/// Gets the bonus value in equivalent number of tricks due to a team bidding at least 10 tricks.
-pub fn high_trick_bonus(bid1: Bid, bid2: Bid) -> u8 {
-    if num_tricks(bid1) + num_tricks(bid2) >= 10 {
-        10
+/// Gets the bonus value in equivalent number of tricks due to a team bidding
+/// at least the high trick threshold of the rules.
+pub fn high_trick_bonus(bid1: Bid, bid2: Bid, rules: &ScoringRules) -> u8 {
+    if num_tricks(bid1) + num_tricks(bid2) >= rules.high_trick_threshold {
+        rules.high_trick_bonus_tens
     } else {
         0
     }
@@ -78,25 +80,53 @@ mod test {
 
     #[test]
     fn minimum_bid() {
+        let rules = ScoringRules::default();
         // ensure that the minimum bid is respected
-        assert_eq!(4, num_team_tricks(Bid::Take(0), Bid::Take(1)));
-        assert_eq!(4, num_team_tricks(Bid::Take(1), Bid::Take(2)));
-        assert_eq!(4, num_team_tricks(Bid::Take(3), Bid::Take(0)));
+        assert_eq!(4, num_team_tricks(Bid::Take(0), Bid::Take(1), &rules));
+        assert_eq!(4, num_team_tricks(Bid::Take(1), Bid::Take(2), &rules));
+        assert_eq!(4, num_team_tricks(Bid::Take(3), Bid::Take(0), &rules));
 
         // ensure that over minimum passes through unchanged
-        assert_eq!(5, num_team_tricks(Bid::Take(3), Bid::Take(2)));
-        assert_eq!(5, num_team_tricks(Bid::Take(2), Bid::Take(3)));
-        assert_eq!(5, num_team_tricks(Bid::Take(5), Bid::Take(0)));
+        assert_eq!(5, num_team_tricks(Bid::Take(3), Bid::Take(2), &rules));
+        assert_eq!(5, num_team_tricks(Bid::Take(2), Bid::Take(3), &rules));
+        assert_eq!(5, num_team_tricks(Bid::Take(5), Bid::Take(0), &rules));
+    }
+
+    #[test]
+    fn configured_minimum_bid() {
+        let rules = ScoringRules {
+            min_team_bid: 0,
+            ..ScoringRules::default()
+        };
+        assert_eq!(2, num_team_tricks(Bid::Take(1), Bid::Take(1), &rules));
+        assert_eq!(0, num_team_tricks(Bid::Take(0), Bid::Nil, &rules));
     }
 
     #[test]
     fn ten_for_two() {
+        let rules = ScoringRules::default();
         // should get bonus
-        assert_eq!(10, high_trick_bonus(Bid::Take(4), Bid::Take(6)));
-        assert_eq!(10, high_trick_bonus(Bid::Take(7), Bid::Take(4)));
+        assert_eq!(10, high_trick_bonus(Bid::Take(4), Bid::Take(6), &rules));
+        assert_eq!(10, high_trick_bonus(Bid::Take(7), Bid::Take(4), &rules));
 
         // should not get bonus
-        assert_eq!(0, high_trick_bonus(Bid::Take(3), Bid::Take(6)));
-        assert_eq!(0, high_trick_bonus(Bid::Take(6), Bid::Take(3)));
+        assert_eq!(0, high_trick_bonus(Bid::Take(3), Bid::Take(6), &rules));
+        assert_eq!(0, high_trick_bonus(Bid::Take(6), Bid::Take(3), &rules));
+    }
+
+    #[test]
+    fn configured_high_trick_bonus() {
+        let rules = ScoringRules {
+            high_trick_threshold: 11,
+            ..ScoringRules::default()
+        };
+        assert_eq!(0, high_trick_bonus(Bid::Take(4), Bid::Take(6), &rules));
+        assert_eq!(10, high_trick_bonus(Bid::Take(5), Bid::Take(6), &rules));
+
+        let rules = ScoringRules {
+            high_trick_bonus_tens: 5,
+            ..ScoringRules::default()
+        };
+        assert_eq!(5, high_trick_bonus(Bid::Take(4), Bid::Take(6), &rules));
     }
 }
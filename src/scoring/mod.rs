@@ -1,16 +1,68 @@
 //! Contains functions and types that store and give information about team's scores.
 
+use crate::{player, Player};
+
 mod score;
 pub use score::Score;
 
 pub(crate) mod bid;
 pub use bid::Bid;
 
+mod scoring_rules;
+pub use scoring_rules::ScoringRules;
+
 mod team_round_result;
 pub use team_round_result::TeamRoundResult;
 
+mod win_condition;
+pub use win_condition::WinCondition;
+
 mod bid_util;
 
+/// The parts that make up the value of a team's bid.
+///
+/// The fields sum to the value given by get_bid_value().
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BidValueBreakdown {
+    /// The number of tricks the team bid, which is at least the minimum
+    /// team bid.
+    pub team_tricks: u8,
+    /// The bonus from nil and blind nil bids.
+    pub nil_bonus_total: u8,
+    /// The bonus from bidding many tricks.
+    pub high_trick_bonus: u8,
+}
+
+impl BidValueBreakdown {
+    /// Gets the value of the bid, the sum of its parts.
+    ///
+    /// Saturates at u8::MAX for rules with very large bonuses.
+    pub fn total(self) -> u8 {
+        self.team_tricks
+            .saturating_add(self.nil_bonus_total)
+            .saturating_add(self.high_trick_bonus)
+    }
+}
+
+/// Gets the parts that make up the value of a team's bid.
+pub fn get_bid_value_breakdown(bid1: Bid, bid2: Bid) -> BidValueBreakdown {
+    get_bid_value_breakdown_with_rules(bid1, bid2, &ScoringRules::default())
+}
+
+/// Gets the parts that make up the value of a team's bid under a set
+/// of rules.
+pub fn get_bid_value_breakdown_with_rules(
+    bid1: Bid,
+    bid2: Bid,
+    rules: &ScoringRules,
+) -> BidValueBreakdown {
+    BidValueBreakdown {
+        team_tricks: bid_util::num_team_tricks(bid1, bid2, rules),
+        nil_bonus_total: bid_util::nil_bonus(bid1) + bid_util::nil_bonus(bid2),
+        high_trick_bonus: bid_util::high_trick_bonus(bid1, bid2, rules),
+    }
+}
+
 /// Gets the value of a team's bid.
 ///
 /// This is how many points the team will make if they make their bet divided by 10.
@@ -18,35 +70,210 @@ mod bid_util;
 /// If they bid 5 tricks and one player going nil their value is 15.
 /// If a team bids less than 4 tricks then they effectively bid the minimum of 4.
 pub fn get_bid_value(bid1: Bid, bid2: Bid) -> u8 {
-    bid_util::num_team_tricks(bid1, bid2)
-        + bid_util::nil_bonus(bid1)
-        + bid_util::nil_bonus(bid2)
-        + bid_util::high_trick_bonus(bid1, bid2)
+    get_bid_value_with_rules(bid1, bid2, &ScoringRules::default())
+}
+
+/// Gets the value of a team's bid under a set of rules.
+pub fn get_bid_value_with_rules(
+    bid1: Bid,
+    bid2: Bid,
+    rules: &ScoringRules,
+) -> u8 {
+    get_bid_value_breakdown_with_rules(bid1, bid2, rules).total()
+}
+
+/// Explains what a team must do to make their bid and what they earn
+/// for it, as a sentence for new players.
+///
+/// For example `Take(0)` and `Take(1)` are explained as "Your team must
+/// take at least 4 tricks (minimum bid) and will earn 40 points."
+pub fn explain_bid(bid1: Bid, bid2: Bid, rules: &ScoringRules) -> String {
+    let breakdown = get_bid_value_breakdown_with_rules(bid1, bid2, rules);
+    let bid_tricks = bid_util::num_tricks(bid1) + bid_util::num_tricks(bid2);
+
+    let mut notes = Vec::new();
+    if bid_tricks < breakdown.team_tricks {
+        notes.push("minimum bid");
+    }
+    if breakdown.high_trick_bonus > 0 {
+        notes.push("high trick bonus");
+    }
+    let mut explanation = format!(
+        "Your team must take at least {} trick{}",
+        breakdown.team_tricks,
+        if breakdown.team_tricks == 1 { "" } else { "s" }
+    );
+    if !notes.is_empty() {
+        explanation += &format!(" ({})", notes.join(", "));
+    }
+    if breakdown.nil_bonus_total > 0 {
+        explanation += " without the nil bidder taking any";
+    }
+    explanation
+        + &format!(" and will earn {} points.", breakdown.total() as u32 * 10)
+}
+
+/// Gets every bid that a player may legally make alongside their teammate's
+/// bid and the value of the team's bid if they make it.
+///
+/// A teammate that has not bid yet is treated as bidding 0 tricks.
+pub fn bid_value_table(
+    teammate_bid: Option<Bid>,
+    rules: &ScoringRules,
+) -> Vec<(Bid, u8)> {
+    bid::Generator::default()
+        .filter(|bid| bid.get_compatibility_error(teammate_bid).is_none())
+        .map(|bid| {
+            let teammate_bid = teammate_bid.unwrap_or(Bid::Take(0));
+            (bid, get_bid_value_with_rules(bid, teammate_bid, rules))
+        })
+        .collect()
+}
+
+/// Gets the most that the difference between the two teams' scores can
+/// change by in a single round, in tens.
+///
+/// This is one team making the most valuable bid possible while the other
+/// team fails the most valuable bid possible and has their extras roll over.
+pub fn max_round_swing() -> i64 {
+    max_round_swing_with_rules(&ScoringRules::default())
+}
+
+/// Gets the most that the difference between the two teams' scores can
+/// change by in a single round under a set of rules, in tens.
+pub fn max_round_swing_with_rules(rules: &ScoringRules) -> i64 {
+    let best_value =
+        get_bid_value_with_rules(Bid::BlindNil, Bid::Take(13), rules) as i64;
+    2 * best_value + 10
 }
 
 /// Gets the index of the winning team.
 ///
 /// Returns None if no team has won yet.
 pub fn get_winning_team_index(scores: [Score; 2]) -> Option<u8> {
-    // over 50 tens and more tens than opponent
-    if scores[0].get_tens() >= 50 && scores[0].get_tens() > scores[1].get_tens()
-    {
-        return Some(0);
-    }
-    if scores[1].get_tens() >= 50 && scores[1].get_tens() > scores[0].get_tens()
-    {
-        return Some(1);
+    get_winning_team_index_with_condition(scores, &WinCondition::default())
+}
+
+/// Gets the index of the winning team under a win condition.
+///
+/// Returns None if no team has won yet.
+pub fn get_winning_team_index_with_condition(
+    scores: [Score; 2],
+    condition: &WinCondition,
+) -> Option<u8> {
+    let tens = [scores[0].get_tens(), scores[1].get_tens()];
+
+    // only a team with strictly more tens than its opponent can win
+    let leader = match tens[0].cmp(&tens[1]) {
+        std::cmp::Ordering::Greater => 0,
+        std::cmp::Ordering::Less => 1,
+        std::cmp::Ordering::Equal => return None,
+    };
+    let gap = (tens[0] - tens[1]).abs();
+
+    // over the target or ahead by the mercy gap
+    if tens[leader] >= condition.target_tens || gap >= condition.mercy_gap {
+        Some(leader as u8)
+    } else {
+        None
     }
+}
+
+/// Gets the fewest points a team must gain this round to win the game
+/// at the end of it, assuming their opponent's score does not change.
+///
+/// Only the tens of the scores decide the winner, so the gain is a
+/// multiple of 10.
+/// Is 0 if the team has already won.
+/// Returns None if the team index is invalid or if the gain is more than
+/// a team can make in a single round.
+pub fn needed_this_round(
+    scores: [Score; 2],
+    team: u8,
+    condition: &WinCondition,
+    rules: &ScoringRules,
+) -> Option<i64> {
+    player::team_members(team).ok()?;
+    let tens = scores[team as usize].get_tens();
+    let opponent_tens = scores[1 - team as usize].get_tens();
 
-    // mercy rule
-    if scores[0].get_tens() - scores[1].get_tens() >= 50 {
-        return Some(0);
+    // must lead, and either reach the target or lead by the mercy gap
+    let winning_tens = (opponent_tens + 1).max(
+        condition
+            .target_tens
+            .min(opponent_tens + condition.mercy_gap),
+    );
+    let needed_tens = (winning_tens - tens).max(0);
+    let best_value =
+        get_bid_value_with_rules(Bid::BlindNil, Bid::Take(13), rules) as i64;
+    if needed_tens > best_value {
+        None
+    } else {
+        Some(needed_tens * 10)
     }
-    if scores[1].get_tens() - scores[0].get_tens() >= 50 {
-        return Some(1);
+}
+
+/// Gets the player that bid first in a round, given the dealer of the
+/// first round.
+///
+/// The dealer moves to the next player each round and the player after
+/// the dealer bids first.
+pub fn first_bidder_for_round(
+    starting_dealer: Player,
+    round_index: usize,
+) -> Player {
+    let mut dealer = starting_dealer;
+    for _ in 0..round_index % 4 {
+        dealer = dealer.next();
     }
+    dealer.next()
+}
 
-    None
+/// Gets the scores after each round given the results of every round,
+/// starting from zero.
+pub fn cumulative_scores(results: &[[TeamRoundResult; 2]]) -> Vec<[Score; 2]> {
+    let mut scores = [Score::default(); 2];
+    results
+        .iter()
+        .map(|results| {
+            scores[0] += results[0].get_score();
+            scores[1] += results[1].get_score();
+            scores
+        })
+        .collect()
+}
+
+/// Gets how accurately each player took the number of tricks they bid,
+/// averaged over a number of rounds.
+///
+/// For a single round a player's accuracy is `1 - |taken - target| / 13`,
+/// where the target is zero for a nil or blind nil bid and the number of
+/// tricks bid otherwise.
+/// This is 1.0 for taking exactly the bid and 0.0 for being off by 13.
+/// Every player's accuracy is 0.0 if there are no rounds.
+pub fn player_accuracy(
+    round_results: &[[TeamRoundResult; 2]],
+) -> player::Array<f32> {
+    let mut accuracy = player::Array::from_value(&0.0);
+    if round_results.is_empty() {
+        return accuracy;
+    }
+    for results in round_results.iter() {
+        for (team_index, result) in results.iter().enumerate() {
+            let team = player::team_members(team_index as u8).unwrap();
+            let targets = result.player_targets();
+            for i in 0..2 {
+                let target = targets[i];
+                let error =
+                    (result.tricks_taken[i] as f32 - target as f32).abs();
+                accuracy[team[i]] += 1.0 - error / 13.0;
+            }
+        }
+    }
+    for player in Player::One.iter() {
+        accuracy[player] /= round_results.len() as f32;
+    }
+    accuracy
 }
 
 #[cfg(test)]
@@ -77,11 +304,141 @@ mod test {
         assert_eq!(11 + 10, get_bid_value(Bid::Take(6), Bid::Take(5)));
     }
 
+    #[test]
+    fn raised_high_trick_threshold() {
+        let rules = ScoringRules {
+            high_trick_threshold: 11,
+            ..ScoringRules::default()
+        };
+        assert_eq!(
+            10,
+            get_bid_value_with_rules(Bid::Take(5), Bid::Take(5), &rules)
+        );
+        assert_eq!(
+            11 + 10,
+            get_bid_value_with_rules(Bid::Take(6), Bid::Take(5), &rules)
+        );
+    }
+
     #[test]
     fn best_value() {
         assert_eq!(13 + 20 + 10, get_bid_value(Bid::BlindNil, Bid::Take(13)));
     }
 
+    #[test]
+    fn best_value_breakdown() {
+        let breakdown = get_bid_value_breakdown(Bid::BlindNil, Bid::Take(13));
+        assert_eq!(
+            BidValueBreakdown {
+                team_tricks: 13,
+                nil_bonus_total: 20,
+                high_trick_bonus: 10,
+            },
+            breakdown
+        );
+        assert_eq!(
+            get_bid_value(Bid::BlindNil, Bid::Take(13)),
+            breakdown.total()
+        );
+    }
+
+    #[test]
+    fn needed() {
+        let condition = WinCondition::default();
+        let rules = ScoringRules::default();
+        let needed =
+            |scores, team| needed_this_round(scores, team, &condition, &rules);
+
+        // reaching the target
+        let scores = [Score::new(45, 3), Score::new(30, 0)];
+        assert_eq!(Some(50), needed(scores, 0));
+        assert_eq!(Some(200), needed(scores, 1));
+
+        // reaching the target is not enough without the lead
+        let scores = [Score::new(45, 0), Score::new(52, 0)];
+        assert_eq!(Some(80), needed(scores, 0));
+        assert_eq!(Some(0), needed(scores, 1));
+
+        // leading by the mercy gap
+        let scores = [Score::new(-60, 0), Score::new(-100, 0)];
+        assert_eq!(Some(100), needed(scores, 0));
+
+        // too far from the target to win this round
+        let scores = [Score::default(), Score::default()];
+        assert_eq!(None, needed(scores, 0));
+
+        assert_eq!(None, needed(scores, 2));
+    }
+
+    #[test]
+    fn explain() {
+        let rules = ScoringRules::default();
+        assert_eq!(
+            "Your team must take at least 4 tricks (minimum bid) and will \
+            earn 40 points.",
+            explain_bid(Bid::Take(0), Bid::Take(1), &rules)
+        );
+        assert_eq!(
+            "Your team must take at least 5 tricks and will earn 50 points.",
+            explain_bid(Bid::Take(2), Bid::Take(3), &rules)
+        );
+        assert_eq!(
+            "Your team must take at least 11 tricks (high trick bonus) \
+            without the nil bidder taking any and will earn 410 points.",
+            explain_bid(Bid::Take(11), Bid::BlindNil, &rules)
+        );
+
+        let rules = ScoringRules {
+            min_team_bid: 0,
+            ..ScoringRules::default()
+        };
+        assert_eq!(
+            "Your team must take at least 1 trick and will earn 10 points.",
+            explain_bid(Bid::Take(0), Bid::Take(1), &rules)
+        );
+    }
+
+    #[test]
+    fn value_table() {
+        let rules = ScoringRules::default();
+
+        let table = bid_value_table(Some(Bid::Take(6)), &rules);
+        assert!(table.contains(&(Bid::Take(4), 20)));
+        assert!(table.contains(&(Bid::Nil, 16)));
+        assert!(table.contains(&(Bid::Take(7), 23)));
+        assert!(table.iter().all(|(bid, _)| *bid != Bid::Take(8)));
+
+        // nil can not be bid alongside a nil
+        let table = bid_value_table(Some(Bid::Nil), &rules);
+        assert_eq!(14, table.len());
+        assert!(table.contains(&(Bid::Take(2), 14)));
+
+        let table = bid_value_table(None, &rules);
+        assert_eq!(16, table.len());
+        assert!(table.contains(&(Bid::BlindNil, 24)));
+    }
+
+    #[test]
+    fn round_swing() {
+        assert_eq!(2 * (13 + 20 + 10) + 10, max_round_swing());
+
+        let rules = ScoringRules {
+            high_trick_bonus_tens: 0,
+            ..ScoringRules::default()
+        };
+        assert_eq!(2 * (13 + 20) + 10, max_round_swing_with_rules(&rules));
+    }
+
+    #[test]
+    fn breakdown_total_saturates() {
+        let breakdown = BidValueBreakdown {
+            team_tricks: 13,
+            nil_bonus_total: 20,
+            high_trick_bonus: 250,
+        };
+        assert_eq!(u8::MAX, breakdown.total());
+    }
+
     #[test]
     fn no_winner() {
         let scores_array = [
@@ -110,4 +467,145 @@ mod test {
             assert_eq!(Some(1), get_winning_team_index([*loser, *winner]));
         }
     }
+
+    #[test]
+    fn accuracy() {
+        let round_results = [
+            [
+                TeamRoundResult {
+                    bids: [Bid::Nil, Bid::Take(5)],
+                    tricks_taken: [0, 7],
+                },
+                TeamRoundResult {
+                    bids: [Bid::Take(3), Bid::Take(3)],
+                    tricks_taken: [3, 3],
+                },
+            ],
+            [
+                TeamRoundResult {
+                    bids: [Bid::BlindNil, Bid::Take(4)],
+                    tricks_taken: [0, 4],
+                },
+                TeamRoundResult {
+                    bids: [Bid::Take(3), Bid::Take(2)],
+                    tricks_taken: [0, 9],
+                },
+            ],
+        ];
+        let accuracy = player_accuracy(&round_results);
+        assert_eq!(1.0, accuracy[Player::One]);
+        assert!((accuracy[Player::Three] - (1.0 - 1.0 / 13.0)).abs() < 1e-6);
+        assert!((accuracy[Player::Two] - (1.0 - 1.5 / 13.0)).abs() < 1e-6);
+        assert!((accuracy[Player::Four] - (1.0 - 3.5 / 13.0)).abs() < 1e-6);
+
+        assert_eq!(0.0, player_accuracy(&[])[Player::One]);
+    }
+
+    #[test]
+    fn shorter_game() {
+        let condition = WinCondition {
+            target_tens: 30,
+            mercy_gap: 30,
+        };
+        let no_winner_array = [
+            (Score::default(), Score::new(29, 9)),
+            (Score::new(31, 0), Score::new(31, 5)),
+            (Score::new(-5, 0), Score::new(24, 0)),
+        ];
+        for (score1, score2) in no_winner_array.iter() {
+            for scores in [[*score1, *score2], [*score2, *score1]].iter() {
+                assert_eq!(
+                    None,
+                    get_winning_team_index_with_condition(*scores, &condition)
+                );
+            }
+        }
+
+        let winner_loser_array = [
+            (Score::new(30, 0), Score::new(29, 5)),
+            (Score::new(31, 5), Score::new(30, 0)),
+            (Score::new(25, 5), Score::new(-5, 0)),
+        ];
+        for (winner, loser) in winner_loser_array.iter() {
+            assert_eq!(
+                Some(0),
+                get_winning_team_index_with_condition(
+                    [*winner, *loser],
+                    &condition
+                )
+            );
+            assert_eq!(
+                Some(1),
+                get_winning_team_index_with_condition(
+                    [*loser, *winner],
+                    &condition
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn both_negative() {
+        let no_winner_array = [
+            (Score::new(-60, 0), Score::new(-15, 0)),
+            (Score::new(-100, 0), Score::new(-100, 5)),
+        ];
+        for (score1, score2) in no_winner_array.iter() {
+            assert_eq!(None, get_winning_team_index([*score1, *score2]));
+            assert_eq!(None, get_winning_team_index([*score2, *score1]));
+        }
+
+        assert_eq!(
+            Some(1),
+            get_winning_team_index([Score::new(-60, 0), Score::new(-5, 0)])
+        );
+        assert_eq!(
+            Some(0),
+            get_winning_team_index([Score::new(-5, 0), Score::new(-60, 0)])
+        );
+    }
+
+    #[test]
+    fn cumulative() {
+        let results = [
+            [
+                TeamRoundResult {
+                    bids: [Bid::Take(3), Bid::Take(2)],
+                    tricks_taken: [4, 3],
+                },
+                TeamRoundResult {
+                    bids: [Bid::Nil, Bid::Take(5)],
+                    tricks_taken: [1, 5],
+                },
+            ],
+            [
+                TeamRoundResult {
+                    bids: [Bid::Take(4), Bid::Take(4)],
+                    tricks_taken: [2, 3],
+                },
+                TeamRoundResult {
+                    bids: [Bid::Take(3), Bid::Take(4)],
+                    tricks_taken: [4, 4],
+                },
+            ],
+        ];
+        assert!(cumulative_scores(&[]).is_empty());
+        assert_eq!(
+            vec![
+                [Score::new(5, 2), Score::new(-15, 1)],
+                [Score::new(-3, 2), Score::new(-8, 2)],
+            ],
+            cumulative_scores(&results)
+        );
+    }
+
+    #[test]
+    fn first_bidder() {
+        assert_eq!(Player::Two, first_bidder_for_round(Player::One, 0));
+        assert_eq!(Player::Three, first_bidder_for_round(Player::One, 1));
+        assert_eq!(Player::One, first_bidder_for_round(Player::One, 3));
+        assert_eq!(Player::Two, first_bidder_for_round(Player::One, 4));
+        assert_eq!(Player::One, first_bidder_for_round(Player::Four, 0));
+        assert_eq!(Player::Four, first_bidder_for_round(Player::Four, 1003));
+    }
 }
@@ -1,5 +1,8 @@
+use super::ScoringRules;
+
 /// Represents a team's score.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Score {
     tens: i64,
     extras: u8,
@@ -29,24 +32,66 @@ impl Score {
     }
 
     /// Adds a number of extras to this score.
+    ///
+    /// Every 10 extras are a penalty of 10 tens.
     pub fn add_extras(&mut self, num_extras: u8) {
+        self.add_extras_with_rules(num_extras, &ScoringRules::default());
+    }
+
+    /// Adds a number of extras to this score under a set of rules.
+    ///
+    /// Every 10 extras are either a penalty or a gain of 10 tens,
+    /// depending on the rules.
+    pub fn add_extras_with_rules(
+        &mut self,
+        num_extras: u8,
+        rules: &ScoringRules,
+    ) {
         self.extras += num_extras;
         while self.extras >= 10 {
-            self.sub_tens(10);
+            if rules.extras_roll_over_as_gain {
+                self.add_tens(10);
+            } else {
+                self.sub_tens(10);
+            }
             self.extras -= 10;
         }
     }
 
+    /// Adds another score to this score under a set of rules.
+    ///
+    /// Is the same as `+=` under the default rules.
+    pub fn add_with_rules(&mut self, other: Self, rules: &ScoringRules) {
+        self.tens += other.tens;
+        self.add_extras_with_rules(other.extras, rules);
+    }
+
     /// Converts this score to an integer that can be displayed on a score board.
     pub fn to_display_int(self) -> i64 {
         self.tens * 10
             + if self.tens < 0 {
-                -1 * self.extras as i64
+                -(self.extras as i64)
             } else {
                 self.extras as i64
             }
     }
 
+    /// Compares two scores by how they are displayed on a score board.
+    ///
+    /// This is the same as comparing the results of to_display_int(),
+    /// so extras raise a positive score but lower a negative score.
+    pub fn cmp_for_display(self, other: Self) -> std::cmp::Ordering {
+        self.to_display_int().cmp(&other.to_display_int())
+    }
+
+    /// Gets how far ahead this score is of another on a score board.
+    ///
+    /// This is the difference of the results of to_display_int(), which
+    /// unlike `-` is not a Score and can not be added back to a Score.
+    pub fn display_difference(self, other: Self) -> i64 {
+        self.to_display_int() - other.to_display_int()
+    }
+
     /// Gets the net number of tricks (adjusted by nils and groups of 10 extras) gotten by this score.
     pub fn get_tens(self) -> i64 {
         self.tens
@@ -58,6 +103,19 @@ impl Score {
     }
 }
 
+/// Scores are ordered by cmp_for_display().
+impl Ord for Score {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.cmp_for_display(*other)
+    }
+}
+
+impl PartialOrd for Score {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl std::ops::AddAssign for Score {
     fn add_assign(&mut self, other: Self) {
         self.tens += other.tens;
@@ -75,9 +133,34 @@ impl std::ops::Add for Score {
     }
 }
 
+/// Subtraction is the inverse of addition, so `(a - b) + b == a`.
+///
+/// If there are not enough extras to subtract then 10 extras are borrowed,
+/// which undoes the penalty of 10 tens that adding them would have caused.
+impl std::ops::SubAssign for Score {
+    fn sub_assign(&mut self, other: Self) {
+        self.tens -= other.tens;
+        if self.extras < other.extras {
+            self.tens += 10;
+            self.extras += 10;
+        }
+        self.extras -= other.extras;
+    }
+}
+
+impl std::ops::Sub for Score {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        let mut copy = self;
+        copy -= other;
+        copy
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::Score;
+    use super::{Score, ScoringRules};
 
     #[test]
     fn default() {
@@ -141,4 +224,94 @@ mod test {
         assert_eq!(10, score.get_tens());
         assert_eq!(0, score.get_extras());
     }
+
+    #[test]
+    fn sub() {
+        let score = Score::new(20, 5) - Score::new(24, 3);
+        assert_eq!(-4, score.get_tens());
+        assert_eq!(2, score.get_extras());
+    }
+
+    #[test]
+    fn sub_extras_borrow() {
+        let mut score = Score::new(10, 3);
+        score -= Score::new(0, 8);
+        assert_eq!(20, score.get_tens());
+        assert_eq!(5, score.get_extras());
+        assert_eq!(Score::new(10, 3), score + Score::new(0, 8));
+    }
+
+    #[test]
+    fn sub_extras_exact() {
+        let score = Score::new(10, 0) - Score::new(0, 5);
+        assert_eq!(20, score.get_tens());
+        assert_eq!(5, score.get_extras());
+
+        let score = Score::new(10, 5) - Score::new(0, 5);
+        assert_eq!(10, score.get_tens());
+        assert_eq!(0, score.get_extras());
+    }
+
+    #[test]
+    fn display_difference() {
+        assert_eq!(95, Score::new(20, 5).display_difference(Score::new(11, 0)));
+        assert_eq!(-38, Score::new(-2, 3).display_difference(Score::new(1, 5)));
+        assert_eq!(0, Score::new(4, 2).display_difference(Score::new(4, 2)));
+    }
+
+    #[test]
+    fn add_with_rules() {
+        let gain = ScoringRules {
+            extras_roll_over_as_gain: true,
+            ..ScoringRules::default()
+        };
+
+        let mut penalized = Score::new(20, 8);
+        penalized.add_with_rules(Score::new(5, 3), &ScoringRules::default());
+        assert_eq!(Score::new(20, 8) + Score::new(5, 3), penalized);
+        assert_eq!(15, penalized.get_tens());
+        assert_eq!(1, penalized.get_extras());
+
+        let mut gained = Score::new(20, 8);
+        gained.add_with_rules(Score::new(5, 3), &gain);
+        assert_eq!(35, gained.get_tens());
+        assert_eq!(1, gained.get_extras());
+    }
+
+    #[test]
+    fn ordering() {
+        // same tens
+        assert!(Score::new(20, 5) > Score::new(20, 3));
+        assert!(Score::new(-20, 5) < Score::new(-20, 3));
+        assert_eq!(
+            std::cmp::Ordering::Equal,
+            Score::new(20, 5).cmp_for_display(Score::new(20, 5))
+        );
+
+        // different tens
+        assert!(Score::new(21, 0) > Score::new(20, 9));
+        assert!(Score::new(-21, 0) < Score::new(-20, 9));
+
+        // different signs
+        assert!(Score::new(0, 0) > Score::new(-1, 0));
+        assert!(Score::new(0, 9) > Score::new(-1, 9));
+        assert!(Score::new(-1, 0) < Score::new(0, 0));
+
+        let mut scores = vec![
+            Score::new(3, 2),
+            Score::new(-3, 2),
+            Score::new(0, 1),
+            Score::new(-3, 0),
+        ];
+        scores.sort();
+        assert_eq!(
+            vec![
+                Score::new(-3, 2),
+                Score::new(-3, 0),
+                Score::new(0, 1),
+                Score::new(3, 2)
+            ],
+            scores
+        );
+    }
 }
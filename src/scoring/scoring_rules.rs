@@ -0,0 +1,41 @@
+/// Rules that change how a round is scored.
+///
+/// The default rules are the variant described in the crate documentation.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ScoringRules {
+    /// If nil bids are scored separately from the rest of the team's bid.
+    ///
+    /// When true a successful nil scores even if the team does not take the
+    /// number of tricks that they bid, and a failed nil does not cause the
+    /// rest of the team's bid to fail.
+    pub nil_independent_of_team: bool,
+    /// If every 10 extras add 10 tens instead of subtracting them.
+    ///
+    /// By default accumulating 10 extras is a penalty of 10 tens.
+    pub extras_roll_over_as_gain: bool,
+    /// The fewest tricks that a team's bid is worth.
+    ///
+    /// A team that bids fewer tricks must still take this many.
+    /// Defaults to 4.
+    pub min_team_bid: u8,
+    /// The fewest tricks that a team must bid to earn the high trick bonus.
+    ///
+    /// Defaults to 10.
+    pub high_trick_threshold: u8,
+    /// The value of the high trick bonus in tens.
+    ///
+    /// Defaults to 10.
+    pub high_trick_bonus_tens: u8,
+}
+
+impl Default for ScoringRules {
+    fn default() -> Self {
+        Self {
+            nil_independent_of_team: false,
+            extras_roll_over_as_gain: false,
+            min_team_bid: 4,
+            high_trick_threshold: 10,
+            high_trick_bonus_tens: 10,
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use super::bid_util;
-use super::Score;
+use super::{Score, ScoringRules};
 use crate::player;
 use crate::Bid;
 use crate::Player;
@@ -7,7 +7,8 @@ use crate::Player;
 /// Contains a team's bid and number of tricks taken in a round.
 ///
 /// Is a building block of ScoreBoard.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TeamRoundResult {
     /// The bids of each player in a team for a round.
     pub bids: [Bid; 2],
@@ -42,27 +43,87 @@ impl TeamRoundResult {
         ]
     }
 
+    /// Gets the number of tricks each player in the team is individually
+    /// trying to take.
+    ///
+    /// This is 0 for a nil or blind nil bid and the number of tricks bid
+    /// otherwise.
+    /// These do not include the minimum team bid, so a team whose targets
+    /// sum to less than the minimum must still take the minimum between
+    /// its players that did not bid nil.
+    pub fn player_targets(&self) -> [u8; 2] {
+        [
+            bid_util::num_tricks(self.bids[0]),
+            bid_util::num_tricks(self.bids[1]),
+        ]
+    }
+
+    /// Gets the number of bags, tricks taken over the number required,
+    /// produced by this round.
+    ///
+    /// A team that is set by taking too few tricks has no bags.
+    /// A failed nil does not remove bags, matching get_score().
+    pub fn bags(&self) -> u8 {
+        self.bags_with_rules(&ScoringRules::default())
+    }
+
+    /// Gets the number of bags produced by this round under a set of rules.
+    pub fn bags_with_rules(&self, rules: &ScoringRules) -> u8 {
+        let tricks_taken = self.tricks_taken[0] + self.tricks_taken[1];
+        let tricks_required =
+            bid_util::num_team_tricks(self.bids[0], self.bids[1], rules);
+        tricks_taken.saturating_sub(tricks_required)
+    }
+
     /// Gets the change in score caused by this round.
     pub fn get_score(&self) -> Score {
+        self.get_score_with_rules(&ScoringRules::default())
+    }
+
+    /// Gets the change in score caused by this round under a set of rules.
+    pub fn get_score_with_rules(&self, rules: &ScoringRules) -> Score {
         let tricks_taken = self.tricks_taken[0] + self.tricks_taken[1];
         let tricks_required =
-            bid_util::num_team_tricks(self.bids[0], self.bids[1]);
+            bid_util::num_team_tricks(self.bids[0], self.bids[1], rules);
 
-        let failed = (tricks_taken < tricks_required)
-            || (bid_util::is_any_nil(self.bids[0])
-                && self.tricks_taken[0] != 0)
-            || (bid_util::is_any_nil(self.bids[1])
-                && self.tricks_taken[1] != 0);
-        let value = super::get_bid_value(self.bids[0], self.bids[1]);
+        let team_failed = tricks_taken < tricks_required;
+        let nil_failed = [0, 1].map(|i| {
+            bid_util::is_any_nil(self.bids[i]) && self.tricks_taken[i] != 0
+        });
 
         let mut score = Score::default();
-        if failed {
-            score.sub_tens(value);
+        let mut apply = |failed: bool, value: u8| {
+            if failed {
+                score.sub_tens(value);
+            } else {
+                score.add_tens(value);
+            }
+        };
+        if rules.nil_independent_of_team {
+            apply(
+                team_failed,
+                tricks_required
+                    + bid_util::high_trick_bonus(
+                        self.bids[0],
+                        self.bids[1],
+                        rules,
+                    ),
+            );
+            for (failed, bid) in nil_failed.iter().zip(self.bids.iter()) {
+                apply(*failed, bid_util::nil_bonus(*bid));
+            }
         } else {
-            score.add_tens(value);
+            apply(
+                team_failed || nil_failed[0] || nil_failed[1],
+                super::get_bid_value_with_rules(
+                    self.bids[0],
+                    self.bids[1],
+                    rules,
+                ),
+            );
         }
         if tricks_taken > tricks_required {
-            score.add_extras(tricks_taken - tricks_required);
+            score.add_extras_with_rules(tricks_taken - tricks_required, rules);
         }
 
         score
@@ -95,6 +156,7 @@ mod test {
 
         assert_eq!(4, score.get_tens());
         assert_eq!(1, score.get_extras());
+        assert_eq!(1, result.bags());
     }
 
     #[test]
@@ -155,6 +217,7 @@ mod test {
 
         assert_eq!(-5, score.get_tens());
         assert_eq!(0, score.get_extras());
+        assert_eq!(0, result.bags());
     }
 
     #[test]
@@ -216,4 +279,121 @@ mod test {
         assert_eq!(-20, score.get_tens());
         assert_eq!(0, score.get_extras());
     }
+
+    #[test]
+    fn player_targets() {
+        let result = TeamRoundResult {
+            bids: [Bid::Nil, Bid::Take(5)],
+            tricks_taken: [0, 0],
+        };
+        assert_eq!([0, 5], result.player_targets());
+
+        let result = TeamRoundResult {
+            bids: [Bid::Take(3), Bid::BlindNil],
+            tricks_taken: [0, 0],
+        };
+        assert_eq!([3, 0], result.player_targets());
+
+        let result = TeamRoundResult {
+            bids: [Bid::Take(1), Bid::Take(2)],
+            tricks_taken: [0, 0],
+        };
+        assert_eq!([1, 2], result.player_targets());
+    }
+
+    #[test]
+    fn nil_independent_of_team_not_take() {
+        let result = TeamRoundResult {
+            bids: [Bid::Nil, Bid::Take(5)],
+            tricks_taken: [0, 4],
+        };
+        let rules = ScoringRules {
+            nil_independent_of_team: true,
+            ..ScoringRules::default()
+        };
+
+        // the nil busts with the team by default
+        assert_eq!(Score::new(-15, 0), result.get_score());
+        assert_eq!(
+            result.get_score(),
+            result.get_score_with_rules(&ScoringRules::default())
+        );
+
+        // the nil still scores when independent
+        assert_eq!(Score::new(10 - 5, 0), result.get_score_with_rules(&rules));
+    }
+
+    #[test]
+    fn nil_independent_of_team_take() {
+        let result = TeamRoundResult {
+            bids: [Bid::Nil, Bid::Take(4)],
+            tricks_taken: [1, 3],
+        };
+        let rules = ScoringRules {
+            nil_independent_of_team: true,
+            ..ScoringRules::default()
+        };
+
+        // the failed nil does not bust the team's bid when independent
+        assert_eq!(Score::new(-14, 0), result.get_score());
+        assert_eq!(Score::new(4 - 10, 0), result.get_score_with_rules(&rules));
+    }
+
+    #[test]
+    fn extras_roll_over_as_gain() {
+        let result = TeamRoundResult {
+            bids: [Bid::Take(2), Bid::Take(2)],
+            tricks_taken: [4, 3],
+        };
+        let gain = ScoringRules {
+            extras_roll_over_as_gain: true,
+            ..ScoringRules::default()
+        };
+
+        let mut penalized = Score::new(20, 8);
+        let rules = ScoringRules::default();
+        penalized.add_with_rules(result.get_score_with_rules(&rules), &rules);
+        assert_eq!(Score::new(14, 1), penalized);
+
+        let mut gained = Score::new(20, 8);
+        gained.add_with_rules(result.get_score_with_rules(&gain), &gain);
+        assert_eq!(Score::new(34, 1), gained);
+    }
+
+    #[test]
+    fn min_team_bid() {
+        let result = TeamRoundResult {
+            bids: [Bid::Take(1), Bid::Take(1)],
+            tricks_taken: [1, 1],
+        };
+        let rules = ScoringRules {
+            min_team_bid: 0,
+            ..ScoringRules::default()
+        };
+
+        // the team is set under the default minimum of four
+        assert_eq!(Score::new(-4, 0), result.get_score());
+        assert_eq!(Score::new(2, 0), result.get_score_with_rules(&rules));
+
+        let result = TeamRoundResult {
+            bids: [Bid::Take(1), Bid::Take(1)],
+            tricks_taken: [2, 2],
+        };
+        assert_eq!(Score::new(4, 0), result.get_score());
+        assert_eq!(Score::new(2, 2), result.get_score_with_rules(&rules));
+    }
+
+    #[test]
+    fn bags_with_rules() {
+        let result = TeamRoundResult {
+            bids: [Bid::Take(1), Bid::Take(1)],
+            tricks_taken: [2, 2],
+        };
+        let rules = ScoringRules {
+            min_team_bid: 0,
+            ..ScoringRules::default()
+        };
+        assert_eq!(0, result.bags());
+        assert_eq!(2, result.bags_with_rules(&rules));
+    }
 }
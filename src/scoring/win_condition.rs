@@ -0,0 +1,22 @@
+/// The scores at which a team wins the game.
+///
+/// The default condition is the variant described in the crate
+/// documentation.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct WinCondition {
+    /// The number of tens a team needs to win, as long as they have
+    /// strictly more tens than the other team.
+    pub target_tens: i64,
+    /// The number of tens a team needs to lead by to win regardless of
+    /// their score.
+    pub mercy_gap: i64,
+}
+
+impl Default for WinCondition {
+    fn default() -> Self {
+        Self {
+            target_tens: 50,
+            mercy_gap: 50,
+        }
+    }
+}
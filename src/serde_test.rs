@@ -0,0 +1,144 @@
+//! Tests that the data model round trips through serde.
+
+use crate::card::{self, Suite, Value};
+use crate::game::{Action, Event, Notification, Response, Status};
+use crate::{Bid, Card, Error, Player, Score, TeamRoundResult, Trick};
+
+/// Serializes a value to JSON and back, checking that it is unchanged.
+fn round_trip<T>(value: T)
+where
+    T: serde::Serialize
+        + serde::de::DeserializeOwned
+        + PartialEq
+        + std::fmt::Debug,
+{
+    let json = serde_json::to_string(&value).unwrap();
+    let result: T = serde_json::from_str(&json).unwrap();
+    assert_eq!(value, result);
+}
+
+#[test]
+fn card() {
+    for i in 0..52 {
+        let card = Card::from_index(i).unwrap();
+        round_trip(card);
+        round_trip(card.suite);
+        round_trip(card.value);
+    }
+}
+
+#[test]
+fn card_set() {
+    round_trip(card::Set::default());
+    round_trip(card::Set::full());
+    for i in 0..4 {
+        round_trip(card::Set::suite(Suite::from_index(i).unwrap()));
+    }
+    assert_eq!(
+        "8191",
+        serde_json::to_string(&card::Set::suite(Suite::Spade)).unwrap()
+    );
+}
+
+#[test]
+fn invalid_card_set() {
+    let json = (1_u64 << 60).to_string();
+    assert!(serde_json::from_str::<card::Set>(&json).is_err());
+}
+
+#[test]
+fn bid() {
+    for bid in crate::scoring::bid::Generator::default() {
+        round_trip(bid);
+    }
+}
+
+#[test]
+fn score() {
+    round_trip(Score::default());
+    round_trip(Score::new(-20, 5));
+    round_trip(Score::new(43, 9));
+}
+
+#[test]
+fn player() {
+    for player in Player::One.iter() {
+        round_trip(player);
+    }
+}
+
+#[test]
+fn status() {
+    round_trip(Status::WaitingForBid(Player::Two));
+    round_trip(Status::WaitingForNilConfirmation(Player::Three));
+    round_trip(Status::WaitingForPlay(Player::Four));
+    round_trip(Status::GameOver);
+}
+
+#[test]
+fn action() {
+    round_trip(Action::Wait);
+    round_trip(Action::SeeCards);
+    round_trip(Action::AllowNil);
+    round_trip(Action::RejectNil);
+    round_trip(Action::MakeBid(Bid::Take(4)));
+    round_trip(Action::PlayCard(Card::new(Suite::Heart, Value::Queen)));
+    round_trip(Action::Concede);
+}
+
+#[test]
+fn event() {
+    round_trip(Event::SeeCards);
+    round_trip(Event::MakeBid(Bid::BlindNil));
+    round_trip(Event::ApprovesNil(false));
+    round_trip(Event::PlayCard(Card::new(Suite::Club, Value::Number(10))));
+    round_trip(Event::Concede);
+}
+
+#[test]
+fn notification() {
+    round_trip(Notification {
+        player: Player::One,
+        event: Event::MakeBid(Bid::Nil),
+    });
+}
+
+#[test]
+fn response() {
+    round_trip(Response::Ok);
+    round_trip(Response::Cards(card::Set::suite(Suite::Diamond)));
+    round_trip(Response::Err(Error::NotYourTurn));
+    round_trip(Response::Err(Error::NotificationFailed {
+        index: 2,
+        error: Box::new(Error::GameOver(Action::Concede)),
+    }));
+}
+
+#[test]
+fn team_round_result() {
+    round_trip(TeamRoundResult {
+        bids: [Bid::Nil, Bid::Take(5)],
+        tricks_taken: [0, 6],
+    });
+}
+
+#[test]
+fn trick() {
+    let mut trick = Trick::new(Player::Three);
+    round_trip(trick);
+    trick
+        .play_card(Player::Three, Card::new(Suite::Spade, Value::Ace))
+        .unwrap();
+    round_trip(trick);
+}
+
+#[test]
+fn state_snapshot() {
+    use crate::game::State;
+
+    let mut state = State::default();
+    round_trip(state.snapshot());
+    state.handle_event(Player::Two, Event::SeeCards);
+    state.handle_event_at(Player::Two, Event::MakeBid(Bid::Nil), 5);
+    round_trip(state.snapshot());
+}
@@ -1,10 +1,12 @@
-//! Contains the `Trick` struct and related `Status` enum.
+//! Contains the `Trick` struct and related `Status` enum,
+//! along with a compact binary encoding of a round's tricks.
 
 use crate::card::{self, Card, Suite};
-use crate::{player, Player};
+use crate::{player, Error, Player};
 
 /// Contains all of the currently played cards and the starting player.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Trick {
     start_player: Player,
     cards: player::Array<Option<Card>>,
@@ -32,25 +34,42 @@ impl Trick {
     pub fn get_status(&self) -> Status {
         // see if we are waiting for a card to be played
         for player in self.start_player.iter() {
-            println!("{:#?}", player);
             if self.cards[player].is_none() {
                 return Status::Waiting(player);
             }
         }
         // find the winner
-        let mut winner =
-            (self.start_player, self.cards[self.start_player].unwrap());
+        let (player, card) = self.current_winner().unwrap();
+        Status::Won(player, card)
+    }
+
+    /// Gets the player and card that are currently winning this trick,
+    /// even if not every player has played a card yet.
+    ///
+    /// Returns None if no cards have been played.
+    pub fn current_winner(&self) -> Option<(Player, Card)> {
+        let mut winner = (self.start_player, self.cards[self.start_player]?);
         for player in self.start_player.iter().skip(1) {
-            let card = self.cards[player].unwrap();
-            if card.suite == winner.1.suite {
-                if card.value > winner.1.value {
-                    winner = (player, card);
-                }
-            } else if card.suite == Suite::Spade {
+            let card = match self.cards[player] {
+                Some(card) => card,
+                None => break,
+            };
+            if beats(card, winner.1) {
                 winner = (player, card);
             }
         }
-        Status::Won(winner.0, winner.1)
+        Some(winner)
+    }
+
+    /// Gets if a card would currently win this trick if it was played
+    /// by the player whose turn it is.
+    ///
+    /// Any card wins an empty trick as it would lead the trick.
+    pub fn would_win(&self, card: Card) -> bool {
+        match self.current_winner() {
+            Some((_, winning_card)) => beats(card, winning_card),
+            None => true,
+        }
     }
 
     /// Gets the suite that lead this trick.
@@ -64,22 +83,28 @@ impl Trick {
         self.cards[player]
     }
 
+    /// Gets the number of cards that have been played in this trick.
+    pub fn num_cards_played(&self) -> u8 {
+        self.cards.iter().filter(|card| card.is_some()).count() as u8
+    }
+
+    /// Gets if every player has played a card in this trick.
+    pub fn is_complete(&self) -> bool {
+        self.num_cards_played() == 4
+    }
+
     /// Attempts to play a card as a player.
     /// Checks that it is actually this player's turn.
     pub fn play_card(
         &mut self,
         player: Player,
         card: Card,
-    ) -> Result<(), String> {
+    ) -> Result<(), Error> {
         match self.get_status() {
-            Status::Won(_, _) => {
-                Err("Can not play a card into a trick that is already won."
-                    .to_string())
-            }
+            Status::Won(_, _) => Err(Error::TrickComplete),
             Status::Waiting(expected_player) => {
                 if expected_player != player {
-                    Err("Can not play a card when it is not your turn"
-                        .to_string())
+                    Err(Error::NotYourTurn)
                 } else {
                     self.cards[player] = Some(card);
                     Ok(())
@@ -116,6 +141,90 @@ impl Trick {
             }
         }
     }
+
+    /// Takes a player's hand and returns all cards that the player may play.
+    /// Checks that it is actually this player's turn.
+    pub fn get_playable_cards_for(
+        &self,
+        player: Player,
+        hand: card::Set,
+        is_trump_broken: bool,
+    ) -> Result<card::Set, Error> {
+        match self.get_status() {
+            Status::Won(_, _) => Err(Error::TrickComplete),
+            Status::Waiting(expected_player) => {
+                if expected_player != player {
+                    Err(Error::NotYourTurn)
+                } else {
+                    Ok(self.get_playable_cards(hand, is_trump_broken))
+                }
+            }
+        }
+    }
+}
+
+/// Gets if a card played after the winning card of a trick beats it.
+fn beats(card: Card, winning_card: Card) -> bool {
+    if card.suite == winning_card.suite {
+        card.value > winning_card.value
+    } else {
+        card.suite == Suite::Spade
+    }
+}
+
+/// Byte used by `encode_round()` for a card that has not been played yet.
+const NO_CARD: u8 = 0xFF;
+
+/// Encodes the tricks of a round into a compact sequence of bytes.
+///
+/// Each trick takes five bytes: the index of the starting player followed
+/// by the index of each card in the order that they were played.
+/// Cards that have not been played yet are encoded as `0xFF`.
+pub fn encode_round(tricks: &[Trick]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(tricks.len() * 5);
+    for trick in tricks {
+        bytes.push(trick.start_player.to_index());
+        for player in trick.start_player.iter() {
+            bytes.push(trick.cards[player].map_or(NO_CARD, Card::to_index));
+        }
+    }
+    bytes
+}
+
+/// Decodes the tricks of a round that were encoded with `encode_round()`.
+///
+/// Fails if the bytes do not describe at most 13 validly played tricks
+/// or if a card was played more than once.
+pub fn decode_round(bytes: &[u8]) -> Result<Vec<Trick>, Error> {
+    if bytes.len() % 5 != 0 {
+        return Err(Error::InvalidEncodedRoundLength(bytes.len()));
+    }
+    if bytes.len() / 5 > 13 {
+        return Err(Error::TooManyTricks);
+    }
+    let mut played = card::Set::default();
+    bytes
+        .chunks(5)
+        .map(|chunk| {
+            let start_player = Player::from_index(chunk[0])?;
+            let mut trick = Trick::new(start_player);
+            let mut card_missing = false;
+            for (player, index) in start_player.iter().zip(&chunk[1..]) {
+                if *index == NO_CARD {
+                    card_missing = true;
+                } else if card_missing {
+                    return Err(Error::CardAfterMissingCard(player));
+                } else {
+                    let card = Card::from_index(*index)?;
+                    if !played.insert(card) {
+                        return Err(Error::CardPlayedTwice(card));
+                    }
+                    trick.play_card(player, card)?;
+                }
+            }
+            Ok(trick)
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -222,6 +331,55 @@ mod test {
         assert_eq!(trick.get_status(), Status::Waiting(Player::Two));
     }
 
+    #[test]
+    fn current_winner_partial() {
+        let mut trick = Trick::new(Player::Four);
+        assert_eq!(None, trick.current_winner());
+
+        let ace = Card::new(Suite::Diamond, card::Value::Ace);
+        trick.play_card(Player::Four, ace).unwrap();
+        assert_eq!(Some((Player::Four, ace)), trick.current_winner());
+
+        // a later spade overtakes the high card
+        let spade = Card::new(Suite::Spade, card::Value::Number(2));
+        trick.play_card(Player::One, spade).unwrap();
+        assert_eq!(Some((Player::One, spade)), trick.current_winner());
+
+        // an off suite card does not change the winner
+        trick
+            .play_card(Player::Two, Card::new(Suite::Heart, card::Value::Ace))
+            .unwrap();
+        assert_eq!(Some((Player::One, spade)), trick.current_winner());
+        assert_eq!(Status::Waiting(Player::Three), trick.get_status());
+    }
+
+    #[test]
+    fn num_cards_played() {
+        let mut trick = Trick::new(Player::Two);
+        assert_eq!(0, trick.num_cards_played());
+        assert!(!trick.is_complete());
+        for (i, player) in Player::Two.iter().enumerate() {
+            trick
+                .play_card(player, Card::from_index(i as u8).unwrap())
+                .unwrap();
+            assert_eq!(i as u8 + 1, trick.num_cards_played());
+            assert_eq!(i == 3, trick.is_complete());
+        }
+    }
+
+    #[test]
+    fn get_status_is_pure() {
+        let mut trick = Trick::new(Player::Two);
+        trick
+            .play_card(Player::Two, Card::new(Suite::Heart, card::Value::Ace))
+            .unwrap();
+        let copy = trick;
+        for _ in 0..3 {
+            assert_eq!(Status::Waiting(Player::Three), trick.get_status());
+        }
+        assert_eq!(copy, trick);
+    }
+
     #[test]
     fn get_suite() {
         let mut trick = Trick::new(Player::One);
@@ -362,4 +520,107 @@ mod test {
         assert_eq!(non_hearts, trick.get_playable_cards(non_hearts, true));
         assert_eq!(non_hearts, trick.get_playable_cards(non_hearts, false));
     }
+
+    #[test]
+    fn would_win() {
+        let mut trick = Trick::new(Player::One);
+        assert!(trick.would_win(Card::new(Suite::Heart, Value::Number(2))));
+
+        trick
+            .play_card(Player::One, Card::new(Suite::Heart, Value::Queen))
+            .unwrap();
+        // following suit
+        assert!(!trick.would_win(Card::new(Suite::Heart, Value::Number(9))));
+        assert!(trick.would_win(Card::new(Suite::Heart, Value::King)));
+        // off suit
+        assert!(!trick.would_win(Card::new(Suite::Club, Value::Ace)));
+        // trump
+        assert!(trick.would_win(Card::new(Suite::Spade, Value::Number(2))));
+
+        trick
+            .play_card(Player::Two, Card::new(Suite::Spade, Value::Number(5)))
+            .unwrap();
+        assert!(!trick.would_win(Card::new(Suite::Heart, Value::Ace)));
+        assert!(!trick.would_win(Card::new(Suite::Spade, Value::Number(3))));
+        assert!(trick.would_win(Card::new(Suite::Spade, Value::Number(6))));
+    }
+
+    #[test]
+    fn playable_cards_for() {
+        let mut trick = Trick::new(Player::One);
+        let hand: card::Set = [
+            Card::new(Suite::Heart, Value::Number(2)),
+            Card::new(Suite::Spade, Value::King),
+        ]
+        .iter()
+        .collect();
+
+        assert_eq!(
+            Ok(trick.get_playable_cards(hand, false)),
+            trick.get_playable_cards_for(Player::One, hand, false)
+        );
+        assert!(trick
+            .get_playable_cards_for(Player::Two, hand, false)
+            .is_err());
+
+        trick
+            .play_card(Player::One, Card::new(Suite::Club, Value::Ace))
+            .unwrap();
+        assert!(trick
+            .get_playable_cards_for(Player::One, hand, false)
+            .is_err());
+        assert_eq!(
+            Ok(hand),
+            trick.get_playable_cards_for(Player::Two, hand, false)
+        );
+    }
+
+    #[test]
+    fn round_encoding() {
+        // every player plays their suite in order, with the lead rotating
+        let mut tricks = Vec::new();
+        let mut start_player = Player::Two;
+        for value_index in 0..13 {
+            let mut trick = Trick::new(start_player);
+            for player in start_player.iter() {
+                let card =
+                    Card::from_index(player.to_index() * 13 + value_index)
+                        .unwrap();
+                trick.play_card(player, card).unwrap();
+            }
+            tricks.push(trick);
+            start_player = start_player.next();
+        }
+
+        let bytes = encode_round(&tricks);
+        assert_eq!(13 * 5, bytes.len());
+        assert_eq!(tricks, decode_round(&bytes).unwrap());
+
+        // an incomplete trick also round trips
+        tricks[12] = Trick::new(Player::Three);
+        tricks[12]
+            .play_card(Player::Three, Card::new(Suite::Club, Value::Ace))
+            .unwrap();
+        assert_eq!(tricks, decode_round(&encode_round(&tricks)).unwrap());
+    }
+
+    #[test]
+    fn invalid_round_encoding() {
+        // truncated
+        assert_eq!(
+            Err(Error::InvalidEncodedRoundLength(4)),
+            decode_round(&[0, 1, 2, 3])
+        );
+        // invalid player
+        assert!(decode_round(&[4, 1, 2, 3, 4]).is_err());
+        // invalid card
+        assert!(decode_round(&[0, 1, 2, 3, 52]).is_err());
+        // repeated card
+        assert!(decode_round(&[0, 1, 2, 3, 4, 0, 5, 6, 7, 1]).is_err());
+        // card played after a missing card
+        assert_eq!(
+            Err(Error::CardAfterMissingCard(Player::Three)),
+            decode_round(&[0, 1, NO_CARD, 3, 4])
+        );
+    }
 }